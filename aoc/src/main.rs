@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use anyhow::Result;
+use util::runner::Day;
+
+fn main() -> Result<()> {
+    let days = [
+        Day::new::<day_01::Day01>(),
+        Day::new::<day_02::Day02>(),
+        Day::new::<day_03::Day03>(),
+        Day::new::<day_04::Day04>(),
+        Day::new::<day_05::Day05>(),
+        Day::new::<day_06::Day06>(),
+        Day::new::<day_07::Day07>(),
+        Day::new::<day_08::Day08>(),
+        Day::new::<day_09::Day09>(),
+        Day::new::<day_10::Day10>(),
+        Day::new::<day_11::Day11>(),
+        Day::new::<day_12::Day12>(),
+        Day::new::<day_13::Day13>(),
+        Day::new::<day_14::Day14>(),
+        Day::new::<day_15::Day15>(),
+        Day::new::<day_16::Day16>(),
+        Day::new::<day_17::Day17>(),
+        Day::new::<day_18::Day18>(),
+        Day::new::<day_19::Day19>(),
+        Day::new::<day_20::Day20>(),
+        Day::new::<day_21::Day21>(),
+        Day::new::<day_22::Day22>(),
+        Day::new::<day_23::Day23>(),
+        Day::new::<day_24::Day24>(),
+        Day::new::<day_25::Day25>(),
+    ];
+
+    util::runner::aoc(&days, Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/..")))
+}
@@ -1,2 +1,2 @@
 const INPUT: &str = include_str!("../input.txt");
-util::bench!(day_01);
+util::bench!(day_01::Day01);
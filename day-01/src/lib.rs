@@ -2,24 +2,35 @@ use std::io::BufRead;
 
 use anyhow::Result;
 use itertools::Itertools;
+use util::Solution;
 
 type Input = Vec<usize>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader.lines().map(|l| Ok(l?.parse()?)).collect()
-}
+pub struct Day01;
 
-pub fn part1(values: &[usize]) -> usize {
-    values.iter().tuple_windows().filter(|(a, b)| a < b).count()
-}
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|l| Ok(l?.parse()?)).collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        values.iter().tuple_windows().filter(|(a, b)| a < b).count()
+    }
 
-pub fn part2(values: &[usize]) -> usize {
-    part1(
-        &values
-            .windows(3)
-            .map(|arr| arr.iter().sum())
-            .collect::<Vec<_>>(),
-    )
+    fn part2(values: &Self::Input) -> usize {
+        Self::part1(
+            &values
+                .windows(3)
+                .map(|arr| arr.iter().sum())
+                .collect::<Vec<_>>(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -31,16 +42,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day01::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 7);
+        assert_eq!(Day01::part1(&input()), 7);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 5);
+        assert_eq!(Day01::part2(&input()), 5);
     }
 }
@@ -1 +1 @@
-util::main!(day_01);
+util::main!(day_01::Day01);
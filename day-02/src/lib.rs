@@ -3,28 +3,39 @@ use std::io::BufRead;
 use anyhow::Result;
 use command::Command;
 use position::Position;
+use util::Solution;
 
 mod command;
 mod position;
 
 type Input = Vec<Command>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader.lines().map(|l| l?.parse()).collect()
-}
+pub struct Day02;
 
-pub fn part1(values: &[Command]) -> usize {
-    values
-        .iter()
-        .fold(Position::default(), Position::apply1)
-        .combine()
-}
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|l| l?.parse()).collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        values
+            .iter()
+            .fold(Position::default(), Position::apply1)
+            .combine()
+    }
 
-pub fn part2(values: &[Command]) -> usize {
-    values
-        .iter()
-        .fold(Position::default(), Position::apply2)
-        .combine()
+    fn part2(values: &Self::Input) -> usize {
+        values
+            .iter()
+            .fold(Position::default(), Position::apply2)
+            .combine()
+    }
 }
 
 #[cfg(test)]
@@ -36,16 +47,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day02::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 150);
+        assert_eq!(Day02::part1(&input()), 150);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 900);
+        assert_eq!(Day02::part2(&input()), 900);
     }
 }
@@ -1 +1 @@
-util::main!(day_02);
+util::main!(day_02::Day02);
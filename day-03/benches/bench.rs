@@ -1,2 +1,2 @@
 const INPUT: &str = include_str!("../input.txt");
-util::bench!(day_03);
+util::bench!(day_03::Day03);
@@ -1 +1 @@
-util::main!(day_03);
+util::main!(day_03::Day03);
@@ -1 +1 @@
-util::main!(day_04);
+util::main!(day_04::Day04);
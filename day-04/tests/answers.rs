@@ -0,0 +1,3 @@
+const INPUT: &str = include_str!("../input.txt");
+const ANSWERS: &str = include_str!("../answers.txt");
+util::check!(day_04::Day04);
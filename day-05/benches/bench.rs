@@ -1,2 +1,2 @@
 const INPUT: &str = include_str!("../input.txt");
-util::bench!(day_05);
+util::bench!(day_05::Day05);
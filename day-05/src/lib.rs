@@ -3,16 +3,13 @@ use std::io::BufRead;
 use anyhow::Result;
 use itertools::Itertools;
 use line::Line;
+use util::Solution;
 
 mod line;
 mod pos;
 
 type Input = Vec<Line>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader.lines().map(|l| l?.parse()).collect()
-}
-
 fn count_overlaps<'a, I: Iterator<Item = &'a Line>>(iter: I) -> usize {
     iter.flat_map(Line::points)
         .counts()
@@ -21,12 +18,26 @@ fn count_overlaps<'a, I: Iterator<Item = &'a Line>>(iter: I) -> usize {
         .count()
 }
 
-pub fn part1(values: &[Line]) -> usize {
-    count_overlaps(values.iter().filter(|Line(a, b)| a.x == b.x || a.y == b.y))
-}
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part2(values: &[Line]) -> usize {
-    count_overlaps(values.iter())
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|l| l?.parse()).collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        count_overlaps(values.iter().filter(|Line(a, b)| a.x == b.x || a.y == b.y))
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        count_overlaps(values.iter())
+    }
 }
 
 #[cfg(test)]
@@ -38,16 +49,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day05::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 5);
+        assert_eq!(Day05::part1(&input()), 5);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 12);
+        assert_eq!(Day05::part2(&input()), 12);
     }
 }
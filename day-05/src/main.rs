@@ -1 +1 @@
-util::main!(day_05);
+util::main!(day_05::Day05);
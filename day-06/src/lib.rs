@@ -1,19 +1,10 @@
 use std::io::BufRead;
 
 use anyhow::{anyhow, Result};
+use util::Solution;
 
 type Input = Vec<u8>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader
-        .lines()
-        .next()
-        .ok_or_else(|| anyhow!("expected input"))??
-        .split(',')
-        .map(|n| Ok(n.parse()?))
-        .collect()
-}
-
 fn calculate(values: &[u8], times: usize) -> usize {
     let mut counts = [0; 9];
     values.iter().for_each(|&n| counts[n as usize] += 1);
@@ -33,12 +24,32 @@ fn calculate(values: &[u8], times: usize) -> usize {
     counts.into_iter().sum()
 }
 
-pub fn part1(values: &[u8]) -> usize {
-    calculate(values, 80)
-}
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part2(values: &[u8]) -> usize {
-    calculate(values, 256)
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader
+            .lines()
+            .next()
+            .ok_or_else(|| anyhow!("expected input"))??
+            .split(',')
+            .map(|n| Ok(n.parse()?))
+            .collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        calculate(values, 80)
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        calculate(values, 256)
+    }
 }
 
 #[cfg(test)]
@@ -50,16 +61,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day06::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 5934);
+        assert_eq!(Day06::part1(&input()), 5934);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 26984457539);
+        assert_eq!(Day06::part2(&input()), 26984457539);
     }
 }
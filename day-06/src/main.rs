@@ -1 +1 @@
-util::main!(day_06);
+util::main!(day_06::Day06);
@@ -1,37 +1,18 @@
 use std::{cmp::min, io::BufRead};
 
 use anyhow::{anyhow, Result};
-use util::gauss_sum;
+use util::{gauss_sum, Solution};
 
 type Input = Vec<usize>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader
-        .lines()
-        .next()
-        .ok_or_else(|| anyhow!("expected input"))??
-        .split(',')
-        .map(|n| Ok(n.parse()?))
-        .collect()
-}
-
 fn median(values: &[usize]) -> usize {
-    let mut values: Vec<_> = values.iter().copied().collect();
+    let mut values: Vec<_> = values.to_vec();
     values.sort_unstable();
     values[values.len() / 2]
 }
 
 fn abs_diff(a: usize, b: usize) -> usize {
-    if a > b {
-        a - b
-    } else {
-        b - a
-    }
-}
-
-pub fn part1(values: &[usize]) -> usize {
-    let median = median(values);
-    values.iter().map(|n| abs_diff(*n, median)).sum()
+    a.abs_diff(b)
 }
 
 fn avg_floor(values: &[usize]) -> usize {
@@ -41,19 +22,44 @@ fn avg_floor(values: &[usize]) -> usize {
     sum / count
 }
 
-pub fn part2(values: &[usize]) -> usize {
-    let avg = avg_floor(values);
-    let (a, b) = values
-        .iter()
-        .map(|n| {
-            (
-                gauss_sum(abs_diff(*n, avg)),
-                gauss_sum(abs_diff(*n, avg + 1)),
-            )
-        })
-        .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
-        .unwrap();
-    min(a, b)
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader
+            .lines()
+            .next()
+            .ok_or_else(|| anyhow!("expected input"))??
+            .split(',')
+            .map(|n| Ok(n.parse()?))
+            .collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        let median = median(values);
+        values.iter().map(|n| abs_diff(*n, median)).sum()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        let avg = avg_floor(values);
+        let (a, b) = values
+            .iter()
+            .map(|n| {
+                (
+                    gauss_sum(abs_diff(*n, avg)),
+                    gauss_sum(abs_diff(*n, avg + 1)),
+                )
+            })
+            .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
+            .unwrap();
+        min(a, b)
+    }
 }
 
 #[cfg(test)]
@@ -65,7 +71,7 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day07::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
@@ -75,7 +81,7 @@ mod test {
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 37);
+        assert_eq!(Day07::part1(&input()), 37);
     }
 
     #[test]
@@ -92,6 +98,6 @@ mod test {
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 168);
+        assert_eq!(Day07::part2(&input()), 168);
     }
 }
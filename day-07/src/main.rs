@@ -1 +1 @@
-util::main!(day_07);
+util::main!(day_07::Day07);
@@ -4,28 +4,13 @@ use anyhow::Result;
 
 use segment_display::SegmentDisplay;
 use swap_remove_pred::SwapRemovePred;
+use util::Solution;
 
 mod segment_display;
 mod swap_remove_pred;
 
 type Input = Vec<SegmentDisplay>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader.lines().map(|l| l?.parse()).collect()
-}
-
-pub fn part1(values: &[SegmentDisplay]) -> usize {
-    values
-        .iter()
-        .flat_map(|v| {
-            v.1.iter().copied().map(|v| {
-                let count = v.count_ones();
-                (count == 2 || count == 3 || count == 4 || count == 7) as usize
-            })
-        })
-        .sum()
-}
-
 fn find_value(input: &SegmentDisplay) -> usize {
     let mut test_values = input.0.clone();
     let mut configs = [0; 10];
@@ -53,8 +38,34 @@ fn find_value(input: &SegmentDisplay) -> usize {
         .fold(0, |acc, v| acc * 10 + v)
 }
 
-pub fn part2(values: &[SegmentDisplay]) -> usize {
-    values.iter().map(find_value).sum()
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|l| l?.parse()).collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        values
+            .iter()
+            .flat_map(|v| {
+                v.1.iter().copied().map(|v| {
+                    let count = v.count_ones();
+                    (count == 2 || count == 3 || count == 4 || count == 7) as usize
+                })
+            })
+            .sum()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        values.iter().map(find_value).sum()
+    }
 }
 
 #[cfg(test)]
@@ -66,16 +77,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day08::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 26);
+        assert_eq!(Day08::part1(&input()), 26);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 61229);
+        assert_eq!(Day08::part2(&input()), 61229);
     }
 }
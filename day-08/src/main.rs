@@ -1 +1 @@
-util::main!(day_08);
+util::main!(day_08::Day08);
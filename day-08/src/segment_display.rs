@@ -18,8 +18,8 @@ impl FromStr for SegmentDisplay {
             .split_once('|')
             .ok_or(anyhow!("Could not find separator"))?;
         Ok(SegmentDisplay(
-            test.trim().split_whitespace().map(to_bits).collect(),
-            values.trim().split_whitespace().map(to_bits).collect(),
+            test.split_whitespace().map(to_bits).collect(),
+            values.split_whitespace().map(to_bits).collect(),
         ))
     }
 }
@@ -1,77 +1,73 @@
-use std::{collections::HashSet, io::Read};
+use std::{collections::HashSet, io::BufRead};
 
 use anyhow::Result;
-use util::grid::Grid;
+use util::{
+    grid::{Grid, Point},
+    Solution,
+};
 
 type Input = Grid<u8>;
 
-pub fn read_input(mut reader: impl Read) -> Result<Input> {
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
-    Ok(buf.parse()?)
+fn is_low_point(grid: &Input, pos: Point) -> bool {
+    let val = grid[pos];
+    grid.neighbors(pos).all(|(_, &neighbor)| val < neighbor)
 }
 
-fn neighbors(x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> {
-    [(x - 1, y), (x, y - 1), (x + 1, y), (x, y + 1)].into_iter()
-}
+pub struct Day09;
 
-fn is_low_point(grid: &Input, x: isize, y: isize) -> bool {
-    let val = grid.get(x, y).unwrap();
-    for (x, y) in neighbors(x, y) {
-        if grid.get(x, y).map_or(false, |neighbor| val >= neighbor) {
-            return false;
-        }
-    }
-    true
-}
+impl Solution for Day09 {
+    const DAY: u8 = 9;
 
-pub fn part1(values: &Input) -> usize {
-    let mut total = 0;
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    for x in 0..values.width() {
-        for y in 0..values.height() {
-            if is_low_point(values, x, y) {
-                total += 1 + *values.get(x, y).unwrap() as usize;
-            }
-        }
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        Ok(buf.parse()?)
     }
 
-    total
-}
+    fn part1(values: &Self::Input) -> usize {
+        values
+            .iter()
+            .filter(|&(pos, _)| is_low_point(values, pos))
+            .map(|(_, &val)| 1 + val as usize)
+            .sum()
+    }
 
-pub fn part2(values: &Input) -> usize {
-    let mut basins: Vec<usize> = Vec::new();
+    fn part2(values: &Self::Input) -> usize {
+        let mut basins: Vec<usize> = Vec::new();
 
-    for x in 0..values.width() {
-        for y in 0..values.height() {
-            if is_low_point(values, x, y) {
+        for pos in values.positions() {
+            if is_low_point(values, pos) {
                 let mut todo = Vec::new();
                 let mut visited = HashSet::new();
-                todo.push((x, y));
+                todo.push(pos);
 
-                while let Some((x, y)) = todo.pop() {
-                    let val = *values.get(x, y).unwrap();
+                while let Some(pos) = todo.pop() {
+                    let val = values[pos];
                     if val == 9 {
                         continue;
                     }
 
-                    for (x, y) in neighbors(x, y) {
-                        if values.get(x, y).map_or(false, |neighbor| *neighbor > val) {
-                            todo.push((x, y));
+                    for (pos, neighbor) in values.neighbors(pos) {
+                        if *neighbor > val {
+                            todo.push(pos);
                         }
                     }
 
-                    visited.insert((x, y));
+                    visited.insert(pos);
                 }
 
                 basins.push(visited.len());
             }
         }
-    }
 
-    basins.sort_unstable();
-    basins.reverse();
-    basins[0] * basins[1] * basins[2]
+        basins.sort_unstable();
+        basins.reverse();
+        basins[0] * basins[1] * basins[2]
+    }
 }
 
 #[cfg(test)]
@@ -83,16 +79,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day09::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 15);
+        assert_eq!(Day09::part1(&input()), 15);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 1134);
+        assert_eq!(Day09::part2(&input()), 1134);
     }
 }
@@ -1 +1 @@
-util::main!(day_09);
+util::main!(day_09::Day09);
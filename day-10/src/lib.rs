@@ -2,64 +2,75 @@ use std::io::BufRead;
 
 use anyhow::Result;
 use bracket::Token;
+use util::Solution;
 
 mod bracket;
 
 type Input = Vec<Vec<Token>>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    reader
-        .lines()
-        .map(|l| Ok(l?.chars().map(Token::from).collect()))
-        .collect()
-}
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader
+            .lines()
+            .map(|l| Ok(l?.chars().map(Token::from).collect()))
+            .collect()
+    }
 
-pub fn part1(values: &[Vec<Token>]) -> usize {
-    values
-        .iter()
-        .filter_map(|line| {
-            let mut stack = Vec::new();
-            for token in line {
-                match token {
-                    Token::Open(bracket) => stack.push(bracket),
-                    Token::Close(bracket) => {
-                        if bracket != stack.pop().unwrap() {
-                            return Some(bracket.checker_score());
+    fn part1(values: &Self::Input) -> usize {
+        values
+            .iter()
+            .filter_map(|line| {
+                let mut stack = Vec::new();
+                for token in line {
+                    match token {
+                        Token::Open(bracket) => stack.push(bracket),
+                        Token::Close(bracket) => {
+                            if bracket != stack.pop().unwrap() {
+                                return Some(bracket.checker_score());
+                            }
                         }
                     }
                 }
-            }
-            None
-        })
-        .sum()
-}
+                None
+            })
+            .sum()
+    }
 
-pub fn part2(values: &[Vec<Token>]) -> usize {
-    let mut scores: Vec<usize> = values
-        .iter()
-        .filter_map(|line| {
-            let mut stack = Vec::new();
-            for token in line {
-                match token {
-                    Token::Open(bracket) => stack.push(bracket),
-                    Token::Close(bracket) => {
-                        if bracket != stack.pop().unwrap() {
-                            return None;
+    fn part2(values: &Self::Input) -> usize {
+        let mut scores: Vec<usize> = values
+            .iter()
+            .filter_map(|line| {
+                let mut stack = Vec::new();
+                for token in line {
+                    match token {
+                        Token::Open(bracket) => stack.push(bracket),
+                        Token::Close(bracket) => {
+                            if bracket != stack.pop().unwrap() {
+                                return None;
+                            }
                         }
                     }
                 }
-            }
-
-            Some(
-                stack
-                    .into_iter()
-                    .rev()
-                    .fold(0, |acc, bracket| (acc * 5) + bracket.completer_score()),
-            )
-        })
-        .collect();
-    scores.sort_unstable();
-    scores[scores.len() / 2]
+
+                Some(
+                    stack
+                        .into_iter()
+                        .rev()
+                        .fold(0, |acc, bracket| (acc * 5) + bracket.completer_score()),
+                )
+            })
+            .collect();
+        scores.sort_unstable();
+        scores[scores.len() / 2]
+    }
 }
 
 #[cfg(test)]
@@ -71,16 +82,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day10::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 26397);
+        assert_eq!(Day10::part1(&input()), 26397);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 288957);
+        assert_eq!(Day10::part2(&input()), 288957);
     }
 }
@@ -1 +1 @@
-util::main!(day_10);
+util::main!(day_10::Day10);
@@ -1,44 +1,27 @@
-use std::io::Read;
+use std::io::BufRead;
 
 use anyhow::Result;
-use util::grid::Grid;
+use util::{
+    grid::{Grid, Point, ADJACENT},
+    Solution,
+};
 
 type Input = Grid<u8>;
 
-pub fn read_input(mut reader: impl Read) -> Result<Input> {
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
-    Ok(buf.parse()?)
-}
-
-fn neighbors(x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> {
-    [
-        (x - 1, y - 1),
-        (x, y - 1),
-        (x + 1, y - 1),
-        (x - 1, y),
-        (x + 1, y),
-        (x - 1, y + 1),
-        (x, y + 1),
-        (x + 1, y + 1),
-    ]
-    .into_iter()
-}
-
 #[derive(Default)]
-struct Flashes(usize, Vec<(isize, isize)>);
+struct Flashes(usize, Vec<Point>);
 
 impl Flashes {
-    fn inc(&mut self, val: &mut u8, x: isize, y: isize) {
+    fn inc(&mut self, val: &mut u8, pos: Point) {
         *val += 1;
         if *val > 9 {
             *val = 0;
             self.0 += 1;
-            self.1.push((x, y));
+            self.1.push(pos);
         }
     }
 
-    fn pop(&mut self) -> Option<(isize, isize)> {
+    fn pop(&mut self) -> Option<Point> {
         self.1.pop()
     }
 
@@ -50,18 +33,15 @@ impl Flashes {
 fn do_step(grid: &mut Input) -> usize {
     let mut flashes = Flashes::default();
 
-    for x in 0..grid.width() {
-        for y in 0..grid.height() {
-            flashes.inc(grid.get_mut(x, y).unwrap(), x, y);
-        }
+    for (pos, val) in grid.iter_mut() {
+        flashes.inc(val, pos);
     }
 
-    while let Some((x, y)) = flashes.pop() {
-        for (x, y) in neighbors(x, y) {
-            if let Some(val) = grid.get_mut(x, y) {
-                if *val > 0 {
-                    flashes.inc(val, x, y);
-                }
+    while let Some(pos) = flashes.pop() {
+        for pos in grid.positions_around(pos, &ADJACENT) {
+            let val = &mut grid[pos];
+            if *val > 0 {
+                flashes.inc(val, pos);
             }
         }
     }
@@ -69,17 +49,33 @@ fn do_step(grid: &mut Input) -> usize {
     flashes.count()
 }
 
-pub fn part1(values: &Input) -> usize {
-    let mut values = values.clone();
-    (0..100).map(|_| do_step(&mut values)).sum()
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part2(values: &Input) -> usize {
-    let mut values = values.clone();
-    #[allow(clippy::maybe_infinite_iter)]
-    (1..)
-        .find(|_| do_step(&mut values) == values.len())
-        .unwrap()
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        Ok(buf.parse()?)
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        let mut values = values.clone();
+        (0..100).map(|_| do_step(&mut values)).sum()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        let mut values = values.clone();
+        #[allow(clippy::maybe_infinite_iter)]
+        (1..)
+            .find(|_| do_step(&mut values) == values.len())
+            .unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -91,16 +87,56 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day11::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 1656);
+        assert_eq!(Day11::part1(&input()), 1656);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 195);
+        assert_eq!(Day11::part2(&input()), 195);
+    }
+
+    #[test]
+    fn steps() {
+        let mut grid = input();
+        assert_eq!(grid.to_string(), INPUT);
+
+        do_step(&mut grid);
+        assert_eq!(
+            grid.to_string(),
+            "\
+6594254334
+3856965822
+6375667284
+7252447257
+7468496589
+5278635756
+3287952832
+7993992245
+5957959665
+6394862637
+"
+        );
+
+        do_step(&mut grid);
+        assert_eq!(
+            grid.to_string(),
+            "\
+8807476555
+5089087054
+8597889608
+8485769600
+8700908800
+6600088989
+6800005943
+0000007456
+9000000876
+8700006848
+"
+        );
     }
 }
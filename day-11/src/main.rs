@@ -1 +1 @@
-util::main!(day_11);
+util::main!(day_11::Day11);
@@ -1,19 +1,14 @@
-use std::io::Read;
+use std::io::BufRead;
 
 use anyhow::Result;
 use cave_system::{Cave, CaveSystem};
 use itertools::Itertools;
+use util::Solution;
 
 mod cave_system;
 
 type Input = CaveSystem;
 
-pub fn read_input(mut reader: impl Read) -> Result<Input> {
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
-    Ok(buf.parse().unwrap())
-}
-
 fn find_paths<F: Fn(Cave, &[Cave]) -> bool>(system: &Input, can_revisit: F) -> Vec<Vec<Cave>> {
     let mut result = Vec::new();
     let mut todo = vec![vec![Cave::Start]];
@@ -40,30 +35,46 @@ fn find_paths<F: Fn(Cave, &[Cave]) -> bool>(system: &Input, can_revisit: F) -> V
     result
 }
 
-pub fn part1(values: &Input) -> usize {
-    find_paths(values, |next, partial_path| {
-        next.is_large() || !partial_path.contains(&next)
-    })
-    .len()
-}
+pub struct Day12;
 
-pub fn part2(values: &Input) -> usize {
-    find_paths(values, |next, partial_path| {
-        if next.is_large() || !partial_path.contains(&next) {
-            true
-        } else if next.is_small() {
-            partial_path
-                .iter()
-                .copied()
-                .filter(Cave::is_small)
-                .sorted()
-                .dedup_with_count()
-                .all(|(count, _)| count == 1)
-        } else {
-            false
-        }
-    })
-    .len()
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        Ok(buf.parse().unwrap())
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        find_paths(values, |next, partial_path| {
+            next.is_large() || !partial_path.contains(&next)
+        })
+        .len()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        find_paths(values, |next, partial_path| {
+            if next.is_large() || !partial_path.contains(&next) {
+                true
+            } else if next.is_small() {
+                partial_path
+                    .iter()
+                    .copied()
+                    .filter(Cave::is_small)
+                    .sorted()
+                    .dedup_with_count()
+                    .all(|(count, _)| count == 1)
+            } else {
+                false
+            }
+        })
+        .len()
+    }
 }
 
 #[cfg(test)]
@@ -81,21 +92,21 @@ mod test {
     fn inputs() -> Vec<Input> {
         INPUTS
             .iter()
-            .map(|input| read_input(Cursor::new(input)).unwrap())
+            .map(|input| Day12::read_input(Cursor::new(input)).unwrap())
             .collect()
     }
 
     #[test]
     fn test1() {
         for (input, expect) in inputs().into_iter().zip([10, 19, 226]) {
-            assert_eq!(part1(&input), expect);
+            assert_eq!(Day12::part1(&input), expect);
         }
     }
 
     #[test]
     fn test2() {
         for (input, expect) in inputs().into_iter().zip([36, 103, 3509]) {
-            assert_eq!(part2(&input), expect);
+            assert_eq!(Day12::part2(&input), expect);
         }
     }
 }
@@ -1 +1 @@
-util::main!(day_12);
+util::main!(day_12::Day12);
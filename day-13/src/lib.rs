@@ -1,8 +1,10 @@
-use std::{cmp::max, io::BufRead};
+use std::io::BufRead;
 
 use anyhow::Result;
-use paper::{Fold, Pos};
+use paper::{Fold, Pos, Sheet};
+use util::Solution;
 
+mod ocr;
 mod paper;
 
 #[derive(Clone)]
@@ -11,65 +13,57 @@ pub struct Input {
     folds: Vec<Fold>,
 }
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    let mut lines = reader.lines();
-
-    let mut dots = Vec::new();
-    while let Some(Ok(l)) = lines.next() {
-        if l.is_empty() {
-            break;
-        }
-
-        dots.push(l.parse()?);
-    }
-
-    let mut folds = Vec::new();
-    while let Some(Ok(l)) = lines.next() {
-        folds.push(l.parse()?);
-    }
-
-    Ok(Input { dots, folds })
-}
-
 fn do_fold(fold: &Fold, dots: &mut Vec<Pos>) {
     dots.iter_mut().for_each(|dot| *dot = fold.apply_to(*dot));
     dots.sort_unstable();
     dots.dedup();
 }
 
-pub fn part1(values: &Input) -> usize {
-    let mut dots = values.dots.clone();
-    do_fold(values.folds.first().unwrap(), &mut dots);
-    dots.len()
-}
+pub struct Day13;
 
-pub fn part2(values: &Input) -> usize {
-    let mut dots = values.dots.clone();
-    values
-        .folds
-        .iter()
-        .for_each(|fold| do_fold(fold, &mut dots));
-
-    let (w, h) = dots
-        .iter()
-        .fold((0, 0), |(w, h), Pos(x, y)| (max(w, *x), max(h, *y)));
-
-    for y in 0..=h {
-        for x in 0..=w {
-            print!(
-                "{}",
-                if dots.binary_search(&Pos(x, y)).is_ok() {
-                    '#'
-                } else {
-                    ' '
-                }
-            );
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        let mut lines = reader.lines();
+
+        let mut dots = Vec::new();
+        while let Some(Ok(l)) = lines.next() {
+            if l.is_empty() {
+                break;
+            }
+
+            dots.push(l.parse()?);
         }
 
-        println!();
+        let mut folds = Vec::new();
+        while let Some(Ok(l)) = lines.next() {
+            folds.push(l.parse()?);
+        }
+
+        Ok(Input { dots, folds })
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        let mut dots = values.dots.clone();
+        do_fold(values.folds.first().unwrap(), &mut dots);
+        dots.len()
     }
 
-    dots.len()
+    /// Returns the code drawn by the folded dots, or the rendered sheet if it cannot be read.
+    fn part2(values: &Self::Input) -> String {
+        let mut dots = values.dots.clone();
+        values
+            .folds
+            .iter()
+            .for_each(|fold| do_fold(fold, &mut dots));
+        let sheet = Sheet::new(dots);
+        ocr::recognize(&sheet).unwrap_or_else(|| sheet.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -81,16 +75,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day13::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 17);
+        assert_eq!(Day13::part1(&input()), 17);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 16);
+        assert_eq!(Day13::part2(&input()), "#####\n#...#\n#...#\n#...#\n#####");
     }
 }
@@ -1 +1 @@
-util::main!(day_13);
+util::main!(day_13::Day13);
@@ -0,0 +1,108 @@
+use crate::paper::{Pos, Sheet};
+
+const GLYPH_WIDTH: u32 = 4;
+const GLYPH_HEIGHT: u32 = 6;
+/// Glyphs are separated by a single empty column.
+const GLYPH_STRIDE: u32 = GLYPH_WIDTH + 1;
+
+/// The known letters of the Advent of Code font, row by row.
+const GLYPHS: [(&str, char); 17] = [
+    (".##.#..##..######..##..#", 'A'),
+    ("###.#..####.#..##..####.", 'B'),
+    (".##.#..##...#...#..#.##.", 'C'),
+    ("#####...###.#...#...####", 'E'),
+    ("#####...###.#...#...#...", 'F'),
+    (".##.#..##...#.###..#.###", 'G'),
+    ("#..##..######..##..##..#", 'H'),
+    (".###..#...#...#...#..###", 'I'),
+    ("..##...#...#...##..#.##.", 'J'),
+    ("#..##.#.##..#.#.#.#.#..#", 'K'),
+    ("#...#...#...#...#...####", 'L'),
+    (".##.#..##..##..##..#.##.", 'O'),
+    ("###.#..##..####.#...#...", 'P'),
+    ("###.#..##..####.#.#.#..#", 'R'),
+    (".####...#....##....####.", 'S'),
+    ("#..##..##..##..##..#.##.", 'U'),
+    ("####...#..#..#..#...####", 'Z'),
+];
+
+fn glyph(sheet: &Sheet, index: u32) -> Option<char> {
+    let x0 = index * GLYPH_STRIDE;
+    let pattern: String = (0..GLYPH_HEIGHT)
+        .flat_map(|y| (x0..x0 + GLYPH_WIDTH).map(move |x| Pos(x, y)))
+        .map(|pos| if sheet.contains(pos) { '#' } else { '.' })
+        .collect();
+
+    GLYPHS
+        .iter()
+        .find(|(glyph, _)| *glyph == pattern)
+        .map(|(_, c)| *c)
+}
+
+/// Reads the capital letters drawn by the dots of `sheet`.
+///
+/// Returns `None` if the dots do not form a row of known letters.
+pub fn recognize(sheet: &Sheet) -> Option<String> {
+    let (w, h) = sheet.size();
+    if w == 0 || h > GLYPH_HEIGHT {
+        return None;
+    }
+
+    let count = w.div_ceil(GLYPH_STRIDE);
+    let separators_empty = (1..count)
+        .all(|i| (0..GLYPH_HEIGHT).all(|y| !sheet.contains(Pos(i * GLYPH_STRIDE - 1, y))));
+    if !separators_empty {
+        return None;
+    }
+
+    (0..count).map(|i| glyph(sheet, i)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sheet(art: &str) -> Sheet {
+        Sheet::new(
+            art.lines()
+                .zip(0..)
+                .flat_map(|(line, y)| {
+                    line.chars()
+                        .zip(0..)
+                        .filter(|(c, _)| *c == '#')
+                        .map(move |(_, x)| Pos(x, y))
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn letters() {
+        let art = "\
+#..#.####.####.####..##..#..#..##....##
+#..#.#....#.......#.#..#.#..#.#..#....#
+#..#.###..###....#..#....#..#.#.......#
+#..#.#....#.....#...#....#..#.#.......#
+#..#.#....#....#....#..#.#..#.#..#.#..#
+.##..####.#....####..##...##...##...##.";
+        assert_eq!(recognize(&sheet(art)).as_deref(), Some("UEFZCUCJ"));
+    }
+
+    #[test]
+    fn round_trip() {
+        for (glyph, c) in GLYPHS {
+            let art: String = glyph
+                .as_bytes()
+                .chunks(GLYPH_WIDTH as usize)
+                .map(|row| format!("{}\n", std::str::from_utf8(row).unwrap()))
+                .collect();
+            assert_eq!(recognize(&sheet(&art)), Some(c.to_string()));
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(recognize(&sheet("#####\n#...#\n#...#\n#...#\n#####")), None);
+        assert_eq!(recognize(&sheet("")), None);
+    }
+}
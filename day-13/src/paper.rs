@@ -1,4 +1,4 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{cmp::max, fmt::Display, num::ParseIntError, str::FromStr};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pos(pub u32, pub u32);
@@ -53,3 +53,42 @@ impl FromStr for Fold {
         })
     }
 }
+
+/// A sheet of dots, rendered with `#` for dots and `.` for empty space.
+pub struct Sheet(Vec<Pos>);
+
+impl Sheet {
+    /// Sorts and deduplicates the dots, as required for rendering.
+    pub fn new(mut dots: Vec<Pos>) -> Self {
+        dots.sort_unstable();
+        dots.dedup();
+        Self(dots)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.0.binary_search(&pos).is_ok()
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        self.0
+            .iter()
+            .fold((0, 0), |(w, h), Pos(x, y)| (max(w, x + 1), max(h, y + 1)))
+    }
+}
+
+impl Display for Sheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (w, h) = self.size();
+        for y in 0..h {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..w {
+                write!(f, "{}", if self.contains(Pos(x, y)) { '#' } else { '.' })?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,28 +2,39 @@ use std::io::BufRead;
 
 use anyhow::Result;
 use sequence::{Rule, Sequence};
+use util::Solution;
 
 mod sequence;
 
 pub struct Input(Sequence, Vec<Rule>);
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    let mut lines = reader.lines();
-    let sequence = lines.next().unwrap().unwrap().parse().unwrap();
-    assert!(lines.next().unwrap().unwrap().is_empty());
+pub struct Day14;
 
-    Ok(Input(
-        sequence,
-        lines.map(|l| l.unwrap().parse().unwrap()).collect(),
-    ))
-}
+impl Solution for Day14 {
+    const DAY: u8 = 14;
 
-pub fn part1(values: &Input) -> usize {
-    values.0.clone().do_steps(&values.1, 10)
-}
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        let mut lines = reader.lines();
+        let sequence = lines.next().unwrap().unwrap().parse().unwrap();
+        assert!(lines.next().unwrap().unwrap().is_empty());
+
+        Ok(Input(
+            sequence,
+            lines.map(|l| l.unwrap().parse().unwrap()).collect(),
+        ))
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        values.0.clone().do_steps(&values.1, 10)
+    }
 
-pub fn part2(values: &Input) -> usize {
-    values.0.clone().do_steps(&values.1, 40)
+    fn part2(values: &Self::Input) -> usize {
+        values.0.clone().do_steps(&values.1, 40)
+    }
 }
 
 #[cfg(test)]
@@ -35,16 +46,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day14::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 1588);
+        assert_eq!(Day14::part1(&input()), 1588);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 2188189693529);
+        assert_eq!(Day14::part2(&input()), 2188189693529);
     }
 }
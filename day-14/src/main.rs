@@ -1 +1 @@
-util::main!(day_14);
+util::main!(day_14::Day14);
@@ -36,9 +36,7 @@ impl Sequence {
     }
 
     fn score(&self) -> usize {
-        if let MinMaxResult::MinMax(min, max) =
-            self.elements.iter().map(|(_, count)| *count).minmax()
-        {
+        if let MinMaxResult::MinMax(min, max) = self.elements.values().copied().minmax() {
             max - min
         } else {
             panic!()
@@ -113,7 +111,7 @@ mod test {
         cmp_sorted(
             &seq.elements
                 .into_iter()
-                .map(|(elem, count)| (elem.try_into().unwrap(), count))
+                .map(|(elem, count)| (elem.into(), count))
                 .collect::<Vec<_>>(),
             elements,
         );
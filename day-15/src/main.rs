@@ -1 +1 @@
-util::main!(day_15);
+util::main!(day_15::Day15);
@@ -0,0 +1,203 @@
+use thiserror::Error;
+
+use crate::packet::{Content, Packet};
+
+/// How operator packets announce the extent of their sub-packets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// Use the sub-packet count where possible, since its header is four bits shorter.
+    #[default]
+    Auto,
+    /// Length type 0: the total number of bits taken up by the sub-packets.
+    Bits,
+    /// Length type 1: the number of sub-packets.
+    Count,
+}
+
+const BIT_LENGTH_WIDTH: usize = 15;
+const COUNT_WIDTH: usize = 11;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EncodeError {
+    #[error("version {0} does not fit into 3 bits")]
+    Version(u8),
+    #[error("{0} sub-packets do not fit into the {COUNT_WIDTH} bit count field")]
+    TooManyPackets(usize),
+    #[error("{0} bits of sub-packets do not fit into the {BIT_LENGTH_WIDTH} bit length field")]
+    TooManyBits(usize),
+}
+
+#[derive(Debug, Default)]
+struct BitWriter(Vec<bool>);
+
+impl BitWriter {
+    fn push(&mut self, value: usize, width: usize) {
+        self.0
+            .extend((0..width).rev().map(|i| (value >> i) & 1 == 1));
+    }
+
+    fn append(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Hex digits of the written bits, zero-padded to whole bytes.
+    fn to_hex(&self) -> String {
+        self.0
+            .chunks(8)
+            .flat_map(|byte| {
+                let byte = byte
+                    .iter()
+                    .chain([false; 8].iter())
+                    .take(8)
+                    .fold(0, |acc, &bit| (acc << 1) | usize::from(bit));
+                [byte >> 4, byte & 0xF]
+            })
+            .map(|digit| {
+                char::from_digit(digit as u32, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+fn write_literal(writer: &mut BitWriter, value: usize) {
+    let groups = ((usize::BITS - value.leading_zeros()) as usize)
+        .div_ceil(4)
+        .max(1);
+    for i in (0..groups).rev() {
+        writer.push(usize::from(i > 0), 1);
+        writer.push((value >> (i * 4)) & 0xF, 4);
+    }
+}
+
+fn write_packet(
+    writer: &mut BitWriter,
+    packet: &Packet,
+    length_type: LengthType,
+) -> Result<(), EncodeError> {
+    if packet.version > 7 {
+        return Err(EncodeError::Version(packet.version));
+    }
+    writer.push(packet.version.into(), 3);
+
+    let (op, packets) = match &packet.content {
+        Content::Literal(value) => {
+            writer.push(4, 3);
+            write_literal(writer, *value);
+            return Ok(());
+        }
+        Content::Operation(op, packets) => (op, packets),
+    };
+    writer.push(u8::from(*op).into(), 3);
+
+    let mut content = BitWriter::default();
+    for packet in packets {
+        write_packet(&mut content, packet, length_type)?;
+    }
+
+    let count_fits = packets.len() < 1 << COUNT_WIDTH;
+    let bits_fit = content.len() < 1 << BIT_LENGTH_WIDTH;
+    match length_type {
+        LengthType::Count | LengthType::Auto if count_fits => {
+            writer.push(1, 1);
+            writer.push(packets.len(), COUNT_WIDTH);
+        }
+        LengthType::Bits | LengthType::Auto if bits_fit => {
+            writer.push(0, 1);
+            writer.push(content.len(), BIT_LENGTH_WIDTH);
+        }
+        LengthType::Count => return Err(EncodeError::TooManyPackets(packets.len())),
+        _ => return Err(EncodeError::TooManyBits(content.len())),
+    }
+    writer.append(content);
+
+    Ok(())
+}
+
+impl Packet {
+    /// Writes the packet as a hex transmission, using `length_type` for every operator packet.
+    pub fn encode(&self, length_type: LengthType) -> Result<String, EncodeError> {
+        let mut writer = BitWriter::default();
+        write_packet(&mut writer, self, length_type)?;
+        Ok(writer.to_hex())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::packet::Operator;
+
+    use super::*;
+
+    const EXAMPLES: &[&str] = &[
+        "8A004A801A8002F478",
+        "620080001611562C8802118E34",
+        "C0015000016115A2E0802F182340",
+        "A0016C880162017C3686B18A3D4780",
+        "9C0141080250320F1802104A08",
+    ];
+
+    fn literal(version: u8, value: usize) -> Packet {
+        Packet {
+            version,
+            content: Content::Literal(value),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for (hex, length_type) in [
+            ("D2FE28", LengthType::Auto),
+            ("38006F45291200", LengthType::Bits),
+            ("EE00D40C823060", LengthType::Count),
+        ] {
+            let packet: Packet = hex.parse().unwrap();
+            assert_eq!(packet.encode(length_type).unwrap(), hex);
+        }
+
+        for hex in EXAMPLES {
+            let packet: Packet = hex.parse().unwrap();
+            for length_type in [LengthType::Auto, LengthType::Bits, LengthType::Count] {
+                let encoded = packet.encode(length_type).unwrap();
+                assert_eq!(encoded.parse::<Packet>().unwrap(), packet);
+            }
+        }
+
+        let large = literal(0, usize::MAX);
+        assert_eq!(large.encode(LengthType::Auto).unwrap().parse(), Ok(large));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            literal(8, 0).encode(LengthType::Auto),
+            Err(EncodeError::Version(8))
+        );
+
+        let packets = (0..2048).map(|_| literal(0, 0)).collect();
+        let packet = Packet {
+            version: 0,
+            content: Content::Operation(Operator::Sum, packets),
+        };
+        assert_eq!(
+            packet.encode(LengthType::Count),
+            Err(EncodeError::TooManyPackets(2048))
+        );
+        assert!(packet.encode(LengthType::Auto).is_ok());
+
+        let packets = (0..3000).map(|_| literal(0, 0)).collect();
+        let packet = Packet {
+            version: 0,
+            content: Content::Operation(Operator::Sum, packets),
+        };
+        assert_eq!(
+            packet.encode(LengthType::Auto),
+            Err(EncodeError::TooManyBits(33000))
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt::{self, Display, Formatter};
+
+use thiserror::Error;
+
+use crate::packet::{Content, Operator, Packet};
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operator::Sum => "+",
+            Operator::Product => "*",
+            Operator::Min => "min",
+            Operator::Max => "max",
+            Operator::Gt => ">",
+            Operator::Lt => "<",
+            Operator::Eq => "==",
+        })
+    }
+}
+
+/// Renders a packet as an S-expression, e.g. `(== (+ 1 3) (* 2 2))`.
+pub struct SExpr<'a>(pub &'a Packet);
+
+impl Display for SExpr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0.content {
+            Content::Literal(value) => write!(f, "{}", value),
+            Content::Operation(op, packets) => {
+                write!(f, "({}", op)?;
+                for packet in packets {
+                    write!(f, " {}", SExpr(packet))?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Renders a packet as an infix formula, e.g. `((1 + 3) == (2 * 2))`. Minimum and maximum are
+/// written as function calls.
+pub struct Infix<'a>(pub &'a Packet);
+
+impl Display for Infix<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (op, packets) = match &self.0.content {
+            Content::Literal(value) => return write!(f, "{}", value),
+            Content::Operation(op, packets) => (op, packets),
+        };
+
+        let separator = match op {
+            Operator::Min | Operator::Max => {
+                write!(f, "{}", op)?;
+                ", ".to_owned()
+            }
+            _ => format!(" {} ", op),
+        };
+
+        write!(f, "(")?;
+        for (i, packet) in packets.iter().enumerate() {
+            if i > 0 {
+                f.write_str(&separator)?;
+            }
+            write!(f, "{}", Infix(packet))?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EvalError {
+    #[error("`{0}` overflowed")]
+    Overflow(Operator),
+    #[error("`{0}` needs at least one operand")]
+    Empty(Operator),
+    #[error("`{op}` needs exactly two operands, found {found}")]
+    Arity { op: Operator, found: usize },
+}
+
+/// Numbers a packet can be evaluated to.
+pub trait Number: Ord + Sized {
+    fn from_literal(value: usize) -> Self;
+    fn try_add(self, other: Self) -> Option<Self>;
+    fn try_mul(self, other: Self) -> Option<Self>;
+}
+
+impl Number for usize {
+    fn from_literal(value: usize) -> Self {
+        value
+    }
+
+    fn try_add(self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+
+    fn try_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Number for num_bigint::BigUint {
+    fn from_literal(value: usize) -> Self {
+        value.into()
+    }
+
+    fn try_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn try_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+/// Evaluates the expression described by `packet`.
+pub fn evaluate<N: Number>(packet: &Packet) -> Result<N, EvalError> {
+    let (op, packets) = match &packet.content {
+        Content::Literal(value) => return Ok(N::from_literal(*value)),
+        Content::Operation(op, packets) => (*op, packets),
+    };
+
+    let values = packets
+        .iter()
+        .map(evaluate)
+        .collect::<Result<Vec<N>, _>>()?;
+
+    match op {
+        Operator::Sum | Operator::Product | Operator::Min | Operator::Max if values.is_empty() => {
+            Err(EvalError::Empty(op))
+        }
+        Operator::Sum => fold(values, 0, N::try_add, op),
+        Operator::Product => fold(values, 1, N::try_mul, op),
+        Operator::Min => Ok(values.into_iter().min().unwrap()),
+        Operator::Max => Ok(values.into_iter().max().unwrap()),
+        Operator::Gt | Operator::Lt | Operator::Eq => {
+            let [a, b]: [N; 2] = values
+                .try_into()
+                .map_err(|values: Vec<N>| EvalError::Arity {
+                    op,
+                    found: values.len(),
+                })?;
+            let result = match op {
+                Operator::Gt => a > b,
+                Operator::Lt => a < b,
+                _ => a == b,
+            };
+            Ok(N::from_literal(result.into()))
+        }
+    }
+}
+
+fn fold<N: Number>(
+    values: Vec<N>,
+    init: usize,
+    f: impl Fn(N, N) -> Option<N>,
+    op: Operator,
+) -> Result<N, EvalError> {
+    values
+        .into_iter()
+        .try_fold(N::from_literal(init), f)
+        .ok_or(EvalError::Overflow(op))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn literal(value: usize) -> Packet {
+        Packet {
+            version: 0,
+            content: Content::Literal(value),
+        }
+    }
+
+    fn operation(op: Operator, values: &[usize]) -> Packet {
+        Packet {
+            version: 0,
+            content: Content::Operation(op, values.iter().copied().map(literal).collect()),
+        }
+    }
+
+    #[test]
+    fn render() {
+        let packet: Packet = "9C0141080250320F1802104A08".parse().unwrap();
+        assert_eq!(SExpr(&packet).to_string(), "(== (+ 1 3) (* 2 2))");
+        assert_eq!(Infix(&packet).to_string(), "((1 + 3) == (2 * 2))");
+
+        let packet = operation(Operator::Min, &[7, 8, 9]);
+        assert_eq!(SExpr(&packet).to_string(), "(min 7 8 9)");
+        assert_eq!(Infix(&packet).to_string(), "min(7, 8, 9)");
+    }
+
+    #[test]
+    fn errors() {
+        let packet = operation(Operator::Product, &[usize::MAX, 2]);
+        assert_eq!(
+            evaluate::<usize>(&packet),
+            Err(EvalError::Overflow(Operator::Product))
+        );
+
+        let packet = operation(Operator::Gt, &[1, 2, 3]);
+        assert_eq!(
+            evaluate::<usize>(&packet),
+            Err(EvalError::Arity {
+                op: Operator::Gt,
+                found: 3
+            })
+        );
+
+        let packet = operation(Operator::Max, &[]);
+        assert_eq!(
+            evaluate::<usize>(&packet),
+            Err(EvalError::Empty(Operator::Max))
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint() {
+        use num_bigint::BigUint;
+
+        let packet = operation(Operator::Product, &[usize::MAX, 2]);
+        assert_eq!(
+            evaluate::<BigUint>(&packet),
+            Ok(BigUint::from(usize::MAX) * 2_u8)
+        );
+    }
+}
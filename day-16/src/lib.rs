@@ -1,57 +1,51 @@
 use std::io::BufRead;
 
 use anyhow::Result;
-use packet::{Content, Operator, Packet};
+use expression::EvalError;
+use packet::{Content, Packet};
+use util::{Fallible, Solution};
 
-mod packet;
+pub mod encode;
+pub mod expression;
+pub mod packet;
+pub mod stream;
 
-pub fn read_input(reader: impl BufRead) -> Result<Packet> {
-    Ok(reader.lines().next().unwrap()?.parse().unwrap())
-}
+pub struct Day16;
 
-pub fn part1(values: &Packet) -> usize {
-    let mut result = 0;
-    let mut todo = vec![values];
+impl Solution for Day16 {
+    const DAY: u8 = 16;
 
-    while let Some(Packet { version, content }) = todo.pop() {
-        result += *version as usize;
-        if let Content::Operation(_, packets) = content {
-            todo.extend(packets);
-        }
-    }
+    type Input = Packet;
+    type Answer1 = usize;
+    type Answer2 = Fallible<usize, EvalError>;
 
-    result
-}
+    fn read_input(reader: impl BufRead) -> Result<Packet> {
+        Ok(stream::read_packet(reader)?)
+    }
 
-fn binary<U: Into<usize>, F: Fn(&usize, &usize) -> U>(
-    iter: impl Iterator<Item = usize>,
-    op: F,
-) -> usize {
-    let [a, b]: [usize; 2] = iter.collect::<Vec<_>>().try_into().unwrap();
-    op(&a, &b).into()
-}
+    fn part1(values: &Self::Input) -> usize {
+        let mut result = 0;
+        let mut todo = vec![values];
 
-pub fn part2(values: &Packet) -> usize {
-    match &values.content {
-        Content::Literal(l) => *l,
-        Content::Operation(op, packets) => {
-            let iter = packets.iter().map(part2);
-            match op {
-                Operator::Sum => iter.sum(),
-                Operator::Product => iter.product(),
-                Operator::Min => iter.min().unwrap(),
-                Operator::Max => iter.max().unwrap(),
-                Operator::Gt => binary(iter, usize::gt),
-                Operator::Lt => binary(iter, usize::lt),
-                Operator::Eq => binary(iter, usize::eq),
+        while let Some(Packet { version, content }) = todo.pop() {
+            result += *version as usize;
+            if let Content::Operation(_, packets) = content {
+                todo.extend(packets);
             }
         }
+
+        result
+    }
+
+    fn part2(values: &Self::Input) -> Self::Answer2 {
+        expression::evaluate(values).into()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use packet::Operator;
 
     const INPUT1: &[&str] = &[
         "8A004A801A8002F478",
@@ -78,14 +72,30 @@ mod test {
     #[test]
     fn test1() {
         for (input, value) in inputs(INPUT1).zip([16, 12, 23, 31]) {
-            assert_eq!(part1(&input), value);
+            assert_eq!(Day16::part1(&input), value);
         }
     }
 
     #[test]
     fn test2() {
         for (input, value) in inputs(INPUT2).zip([3, 54, 7, 9, 1, 0, 0, 1]) {
-            assert_eq!(part2(&input), value);
+            assert_eq!(Day16::part2(&input), Fallible(Ok(value)));
         }
     }
+
+    #[test]
+    fn overflow() {
+        let literal = |value| Packet {
+            version: 0,
+            content: Content::Literal(value),
+        };
+        let input = Packet {
+            version: 0,
+            content: Content::Operation(Operator::Product, vec![literal(usize::MAX), literal(2)]),
+        };
+        assert_eq!(
+            Day16::part2(&input),
+            Fallible(Err(EvalError::Overflow(Operator::Product)))
+        );
+    }
 }
@@ -1 +1 @@
-util::main!(day_16);
+util::main!(day_16::Day16);
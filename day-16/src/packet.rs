@@ -1,8 +1,8 @@
 use std::str::FromStr;
 
-use nom::Finish;
+use thiserror::Error;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Operator {
     Sum,
     Product,
@@ -13,9 +13,11 @@ pub enum Operator {
     Eq,
 }
 
-impl From<u8> for Operator {
-    fn from(val: u8) -> Self {
-        match val {
+impl TryFrom<u8> for Operator {
+    type Error = u8;
+
+    fn try_from(val: u8) -> Result<Self, u8> {
+        Ok(match val {
             0 => Self::Sum,
             1 => Self::Product,
             2 => Self::Min,
@@ -23,159 +25,242 @@ impl From<u8> for Operator {
             5 => Self::Gt,
             6 => Self::Lt,
             7 => Self::Eq,
-            _ => panic!("unexpected packet id"),
+            _ => return Err(val),
+        })
+    }
+}
+
+impl From<Operator> for u8 {
+    fn from(op: Operator) -> Self {
+        match op {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Min => 2,
+            Operator::Max => 3,
+            Operator::Gt => 5,
+            Operator::Lt => 6,
+            Operator::Eq => 7,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Content {
     Literal(usize),
     Operation(Operator, Vec<Packet>),
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Packet {
     pub version: u8,
     pub content: Content,
 }
 
-mod parse {
-    use std::str::from_utf8;
+/// Reasons a transmission could not be decoded. Offsets count bits from the start of the
+/// transmission.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PacketError {
+    #[error("invalid hex digit {c:?} at bit {offset}")]
+    InvalidHex { offset: usize, c: char },
+    #[error("transmission ended unexpectedly at bit {offset}")]
+    Truncated { offset: usize },
+    #[error("unknown operator type id {id} at bit {offset}")]
+    UnknownTypeId { offset: usize, id: u8 },
+    #[error("literal value at bit {offset} does not fit into {} bits", usize::BITS)]
+    LiteralOverflow { offset: usize },
+    #[error("sub-packets at bit {offset} span {actual} bits instead of {expected}")]
+    LengthMismatch {
+        offset: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("found non-zero bit at {offset} after the outermost packet")]
+    TrailingBits { offset: usize },
+}
 
-    pub use nom::error::Error;
+mod parse {
     use nom::{
-        bits::{
-            bits,
-            complete::{tag, take},
-        },
-        branch::alt,
-        multi::{count, length_count, many_till},
-        sequence::{pair, preceded},
-        IResult, Parser,
+        bits::complete::take, error::ParseError, multi::length_count, sequence::pair, Err, Finish,
+        IResult,
     };
 
-    use super::{Content, Packet};
+    use super::{Content, Operator, Packet, PacketError};
 
     type Bits<'a> = (&'a [u8], usize);
-    type Result<'a, T> = IResult<Bits<'a>, T>;
+    type Result<'a, T> = IResult<Bits<'a>, T, Error<'a>>;
 
-    fn version(input: Bits) -> Result<u8> {
-        take(3_usize)(input)
+    /// Bits left in `input`.
+    fn remaining(input: Bits) -> usize {
+        input.0.len() * 8 - input.1
     }
 
-    fn type_id(input: Bits) -> Result<u8> {
-        take(3_usize)(input)
+    #[derive(Debug)]
+    enum ErrorKind {
+        Truncated,
+        UnknownTypeId(u8),
+        LiteralOverflow,
+        LengthMismatch { expected: usize, actual: usize },
     }
 
-    fn literal(input: Bits) -> Result<usize> {
-        many_till(
-            preceded(tag(1, 1_usize), take(4_usize)),
-            preceded(tag(0, 1_usize), take(4_usize)),
-        )
-        .map(|(list, rem): (Vec<u8>, _)| {
-            let mut result = Vec::new();
-            result.resize(15 - list.len(), 0);
-            result.extend(list);
-            result.push(rem);
-
-            usize::from_be_bytes(
-                result
-                    .chunks(2)
-                    .map(|chunk| (chunk[0] << 4) | chunk[1])
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap(),
-            )
-        })
-        .parse(input)
+    /// Parser error remembering where it occurred, so the bit offset can be recovered once the
+    /// whole transmission is known.
+    #[derive(Debug)]
+    pub struct Error<'a> {
+        input: Bits<'a>,
+        kind: ErrorKind,
+    }
+
+    impl<'a> Error<'a> {
+        fn failure(input: Bits<'a>, kind: ErrorKind) -> Err<Self> {
+            Err::Failure(Self { input, kind })
+        }
+
+        fn into_packet_error(self, data: &[u8]) -> PacketError {
+            let offset = data.len() * 8 - remaining(self.input);
+            match self.kind {
+                ErrorKind::Truncated => PacketError::Truncated { offset },
+                ErrorKind::UnknownTypeId(id) => PacketError::UnknownTypeId { offset, id },
+                ErrorKind::LiteralOverflow => PacketError::LiteralOverflow { offset },
+                ErrorKind::LengthMismatch { expected, actual } => PacketError::LengthMismatch {
+                    offset,
+                    expected,
+                    actual,
+                },
+            }
+        }
     }
 
-    fn literal_type_packet(input: Bits) -> Result<Content> {
-        preceded(tag(4, 3_usize), literal)
-            .map(Content::Literal)
-            .parse(input)
+    impl<'a> ParseError<Bits<'a>> for Error<'a> {
+        // The only way bit-level `take` fails is running out of input.
+        fn from_error_kind(input: Bits<'a>, _: nom::error::ErrorKind) -> Self {
+            Self {
+                input,
+                kind: ErrorKind::Truncated,
+            }
+        }
+
+        fn append(_: Bits<'a>, _: nom::error::ErrorKind, other: Self) -> Self {
+            other
+        }
     }
 
-    fn bit_length_count<'a, F: Parser<Bits<'a>, usize, Error<Bits<'a>>>>(
-        mut count_fn: F,
-    ) -> impl FnMut(Bits<'a>) -> Result<Vec<Packet>> {
-        move |input| {
-            let (mut input, len): (_, usize) = count_fn.parse(input)?;
+    fn version(input: Bits) -> Result<u8> {
+        take(3_usize)(input)
+    }
 
-            // Extra code to circumvent shift overflow when taking more than 64 bits
-            let cmp = {
-                let times = len >> 6;
-                let rem = len & 63;
-                pair::<_, Vec<usize>, usize, _, _, _>(count(take(64_usize), times), take(rem))(
-                    input,
-                )?
-                .0
-            };
+    fn type_id(input: Bits) -> Result<u8> {
+        take(3_usize)(input)
+    }
 
-            let mut result = Vec::new();
-            while input != cmp {
-                let (new_input, value) = parse_bits(input)?;
-                result.push(value);
-                input = new_input;
+    fn literal(start: Bits) -> Result<usize> {
+        let mut input = start;
+        let mut value: usize = 0;
+        loop {
+            let (rest, (more, group)): (_, (u8, usize)) =
+                pair(take(1_usize), take(4_usize))(input)?;
+            if value.leading_zeros() < 4 {
+                return Err(Error::failure(start, ErrorKind::LiteralOverflow));
+            }
+            value = (value << 4) | group;
+            input = rest;
+
+            if more == 0 {
+                return Ok((input, value));
             }
-            Ok((cmp, result))
         }
     }
 
     fn bit_length_content(input: Bits) -> Result<Vec<Packet>> {
-        preceded(tag(0, 1_usize), bit_length_count(take(15_usize)))(input)
+        let (start, len): (_, usize) = take(15_usize)(input)?;
+        if len > remaining(start) {
+            let end = (&start.0[start.0.len()..], 0);
+            return Err(Error::failure(end, ErrorKind::Truncated));
+        }
+
+        let end = remaining(start) - len;
+        let mut input = start;
+        let mut result = Vec::new();
+        while remaining(input) > end {
+            let (rest, packet) = parse_bits(input)?;
+            result.push(packet);
+            input = rest;
+        }
+
+        if remaining(input) != end {
+            let actual = remaining(start) - remaining(input);
+            let kind = ErrorKind::LengthMismatch {
+                expected: len,
+                actual,
+            };
+            return Err(Error::failure(start, kind));
+        }
+        Ok((input, result))
     }
 
     fn count_length_content(input: Bits) -> Result<Vec<Packet>> {
-        preceded(
-            tag(1, 1_usize),
-            length_count(take::<_, u16, _, _>(11_usize), parse_bits),
-        )(input)
+        length_count(take::<_, u16, _, _>(11_usize), parse_bits)(input)
     }
 
-    fn operator_type_packet(input: Bits) -> Result<Content> {
-        pair(type_id, alt((bit_length_content, count_length_content)))
-            .map(|(type_id, packets)| Content::Operation(type_id.into(), packets))
-            .parse(input)
+    fn content(input: Bits) -> Result<Content> {
+        let (rest, id) = type_id(input)?;
+        if id == 4 {
+            let (rest, value) = literal(rest)?;
+            return Ok((rest, Content::Literal(value)));
+        }
+
+        let op = Operator::try_from(id)
+            .map_err(|id| Error::failure(input, ErrorKind::UnknownTypeId(id)))?;
+        let (rest, length_type): (_, u8) = take(1_usize)(rest)?;
+        let (rest, packets) = if length_type == 0 {
+            bit_length_content(rest)?
+        } else {
+            count_length_content(rest)?
+        };
+        Ok((rest, Content::Operation(op, packets)))
     }
 
     fn parse_bits(input: Bits) -> Result<Packet> {
-        pair(version, alt((literal_type_packet, operator_type_packet)))
-            .map(|(version, content)| Packet { version, content })
-            .parse(input)
+        let (input, version) = version(input)?;
+        let (input, content) = content(input)?;
+        Ok((input, Packet { version, content }))
     }
 
-    pub fn parse(input: &[u8]) -> IResult<&[u8], Packet> {
-        bits(parse_bits)(input)
+    /// Parses the single outermost packet of `data`. Only zero padding may follow it.
+    pub fn parse(data: &[u8]) -> std::result::Result<Packet, PacketError> {
+        let (rest, packet) = parse_bits((data, 0))
+            .finish()
+            .map_err(|e| e.into_packet_error(data))?;
+
+        let start = data.len() * 8 - remaining(rest);
+        match (start..data.len() * 8).find(|i| data[i / 8] & (0x80 >> (i % 8)) != 0) {
+            Some(offset) => Err(PacketError::TrailingBits { offset }),
+            None => Ok(packet),
+        }
     }
 
-    pub fn to_bytes(s: &str) -> Vec<u8> {
-        s.as_bytes()
-            .chunks(2)
-            .map(|chunk| {
-                u8::from_str_radix(
-                    from_utf8(&[chunk[0], chunk.get(1).copied().unwrap_or_default()]).unwrap(),
-                    16,
-                )
-                .unwrap()
+    pub fn to_bytes(s: &str) -> std::result::Result<Vec<u8>, PacketError> {
+        let nibbles = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(16)
+                    .map(|d| d as u8)
+                    .ok_or(PacketError::InvalidHex { offset: i * 4, c })
             })
-            .collect()
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(nibbles
+            .chunks(2)
+            .map(|chunk| (chunk[0] << 4) | chunk.get(1).copied().unwrap_or_default())
+            .collect())
     }
 }
 
 impl FromStr for Packet {
-    type Err = parse::Error<Vec<u8>>;
+    type Err = PacketError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let b = parse::to_bytes(s);
-        parse::parse(&b)
-            .finish()
-            .map_err(|parse::Error { input, code }| Self::Err {
-                input: input.to_vec(),
-                code,
-            })
-            .map(|(_, res)| res)
+        parse::parse(&parse::to_bytes(s)?)
     }
 }
 
@@ -243,4 +328,43 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "D2FX28".parse::<Packet>(),
+            Err(PacketError::InvalidHex { offset: 12, c: 'X' })
+        );
+        assert_eq!(
+            "D2FE".parse::<Packet>(),
+            Err(PacketError::Truncated { offset: 16 })
+        );
+        assert_eq!(
+            "D2FE2801".parse::<Packet>(),
+            Err(PacketError::TrailingBits { offset: 31 })
+        );
+        // Bit length of 26, but the two literals take up 27 bits
+        assert_eq!(
+            "38006B45291200".parse::<Packet>(),
+            Err(PacketError::LengthMismatch {
+                offset: 22,
+                expected: 26,
+                actual: 27
+            })
+        );
+        // Bit length of 28, so the zero padding is read as a third sub-packet
+        assert_eq!(
+            "38007345291200".parse::<Packet>(),
+            Err(PacketError::Truncated { offset: 56 })
+        );
+        assert_eq!(
+            "00".parse::<Packet>(),
+            Err(PacketError::Truncated { offset: 7 })
+        );
+        assert_eq!(
+            "D3FFFFFFFFFFFFFFFFFFFFE0".parse::<Packet>(),
+            Err(PacketError::LiteralOverflow { offset: 6 })
+        );
+        assert_eq!(Operator::try_from(4), Err(4));
+    }
 }
@@ -0,0 +1,328 @@
+use std::io::{self, BufRead};
+
+use thiserror::Error;
+
+use crate::packet::{Content, Operator, Packet, PacketError};
+
+#[derive(Debug, Error)]
+pub enum StreamError {
+    #[error(transparent)]
+    Packet(#[from] PacketError),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Reads the bits of a hex transmission, which ends with the first line break or the end of
+/// input.
+struct BitReader<R> {
+    reader: R,
+    nibble: u8,
+    nibble_bits: u8,
+    offset: usize,
+}
+
+impl<R: BufRead> BitReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            nibble: 0,
+            nibble_bits: 0,
+            offset: 0,
+        }
+    }
+
+    fn bit(&mut self) -> Result<Option<bool>, StreamError> {
+        if self.nibble_bits == 0 {
+            let c = match self.reader.fill_buf()?.first() {
+                None | Some(b'\n' | b'\r') => return Ok(None),
+                Some(&c) => c,
+            };
+            self.reader.consume(1);
+
+            let c = char::from(c);
+            self.nibble = c.to_digit(16).ok_or(PacketError::InvalidHex {
+                offset: self.offset,
+                c,
+            })? as u8;
+            self.nibble_bits = 4;
+        }
+
+        self.nibble_bits -= 1;
+        self.offset += 1;
+        Ok(Some((self.nibble >> self.nibble_bits) & 1 == 1))
+    }
+
+    fn read(&mut self, width: usize) -> Result<usize, StreamError> {
+        let offset = self.offset;
+        let mut result = 0;
+        for _ in 0..width {
+            let bit = self.bit()?.ok_or(PacketError::Truncated { offset })?;
+            result = (result << 1) | usize::from(bit);
+        }
+        Ok(result)
+    }
+}
+
+/// Step of a depth first walk through a transmission.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Event {
+    Literal { version: u8, value: usize },
+    Enter { version: u8, op: Operator },
+    Leave,
+}
+
+enum Limit {
+    Bits { start: usize, len: usize },
+    Count(usize),
+}
+
+/// Decodes a transmission into [`Event`]s while reading it, without holding more than the
+/// currently open operator packets in memory.
+pub struct Decoder<R> {
+    bits: BitReader<R>,
+    open: Vec<Limit>,
+    started: bool,
+    done: bool,
+}
+
+impl<R: BufRead> Decoder<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            bits: BitReader::new(reader),
+            open: Vec::new(),
+            started: false,
+            done: false,
+        }
+    }
+
+    fn literal(&mut self) -> Result<usize, StreamError> {
+        let offset = self.bits.offset;
+        let mut value: usize = 0;
+        loop {
+            let more = self.bits.read(1)?;
+            let group = self.bits.read(4)?;
+            if value.leading_zeros() < 4 {
+                return Err(PacketError::LiteralOverflow { offset }.into());
+            }
+            value = (value << 4) | group;
+
+            if more == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn packet(&mut self) -> Result<Event, StreamError> {
+        let version = self.bits.read(3)? as u8;
+        let type_offset = self.bits.offset;
+        let id = self.bits.read(3)? as u8;
+        if id == 4 {
+            let value = self.literal()?;
+            return Ok(Event::Literal { version, value });
+        }
+
+        let op = Operator::try_from(id).map_err(|id| PacketError::UnknownTypeId {
+            offset: type_offset,
+            id,
+        })?;
+        let limit = if self.bits.read(1)? == 0 {
+            let len = self.bits.read(15)?;
+            Limit::Bits {
+                start: self.bits.offset,
+                len,
+            }
+        } else {
+            Limit::Count(self.bits.read(11)?)
+        };
+        self.open.push(limit);
+
+        Ok(Event::Enter { version, op })
+    }
+
+    fn trailing_bits(&mut self) -> Result<(), StreamError> {
+        loop {
+            let offset = self.bits.offset;
+            match self.bits.bit()? {
+                None => return Ok(()),
+                Some(true) => return Err(PacketError::TrailingBits { offset }.into()),
+                Some(false) => {}
+            }
+        }
+    }
+
+    fn step(&mut self) -> Result<Option<Event>, StreamError> {
+        let offset = self.bits.offset;
+        match self.open.last_mut() {
+            Some(&mut Limit::Bits { start, len }) if offset - start >= len => {
+                self.open.pop();
+                if offset - start > len {
+                    return Err(PacketError::LengthMismatch {
+                        offset: start,
+                        expected: len,
+                        actual: offset - start,
+                    }
+                    .into());
+                }
+                return Ok(Some(Event::Leave));
+            }
+            Some(Limit::Count(0)) => {
+                self.open.pop();
+                return Ok(Some(Event::Leave));
+            }
+            Some(Limit::Count(count)) => *count -= 1,
+            Some(Limit::Bits { .. }) => {}
+            None if self.started => {
+                self.trailing_bits()?;
+                return Ok(None);
+            }
+            None => self.started = true,
+        }
+
+        self.packet().map(Some)
+    }
+}
+
+impl<R: BufRead> Iterator for Decoder<R> {
+    type Item = Result<Event, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.step().transpose();
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+/// Builds the packet tree of a transmission from its events.
+pub fn read_packet(reader: impl BufRead) -> Result<Packet, StreamError> {
+    let mut open: Vec<(u8, Operator, Vec<Packet>)> = Vec::new();
+    let mut root = None;
+    for event in Decoder::new(reader) {
+        let packet = match event? {
+            Event::Literal { version, value } => Packet {
+                version,
+                content: Content::Literal(value),
+            },
+            Event::Enter { version, op } => {
+                open.push((version, op, Vec::new()));
+                continue;
+            }
+            Event::Leave => {
+                let (version, op, packets) = open.pop().unwrap();
+                Packet {
+                    version,
+                    content: Content::Operation(op, packets),
+                }
+            }
+        };
+
+        match open.last_mut() {
+            Some((_, _, packets)) => packets.push(packet),
+            None => root = Some(packet),
+        }
+    }
+
+    // The decoder only ends after a complete packet, or with an error
+    Ok(root.unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::encode::LengthType;
+
+    use super::*;
+
+    fn events(hex: &str) -> Vec<Event> {
+        Decoder::new(Cursor::new(hex))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn packet_error(hex: &str) -> PacketError {
+        match read_packet(Cursor::new(hex)) {
+            Err(StreamError::Packet(e)) => e,
+            result => panic!("expected a packet error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(
+            events("38006F45291200\n"),
+            [
+                Event::Enter {
+                    version: 1,
+                    op: Operator::Lt
+                },
+                Event::Literal {
+                    version: 6,
+                    value: 10
+                },
+                Event::Literal {
+                    version: 2,
+                    value: 20
+                },
+                Event::Leave,
+            ]
+        );
+
+        for hex in [
+            "D2FE28",
+            "EE00D40C823060",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ] {
+            assert_eq!(read_packet(Cursor::new(hex)).unwrap(), hex.parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        for hex in [
+            "D2FX28",
+            "D2FE",
+            "D2FE2801",
+            "38006B45291200",
+            "38007345291200",
+            "00",
+            "D3FFFFFFFFFFFFFFFFFFFFE0",
+        ] {
+            assert_eq!(Err(packet_error(hex)), hex.parse::<Packet>());
+        }
+        assert_eq!(packet_error(""), PacketError::Truncated { offset: 0 });
+    }
+
+    #[test]
+    fn long() {
+        let inner = Packet {
+            version: 1,
+            content: Content::Operation(
+                Operator::Sum,
+                (0..300)
+                    .map(|i| Packet {
+                        version: 2,
+                        content: Content::Literal(i),
+                    })
+                    .collect(),
+            ),
+        };
+        let outer = Packet {
+            version: 3,
+            content: Content::Operation(Operator::Max, (0..300).map(|_| inner.clone()).collect()),
+        };
+
+        let hex = outer.encode(LengthType::Count).unwrap();
+        let versions: usize = Decoder::new(Cursor::new(hex))
+            .map(|event| match event.unwrap() {
+                Event::Literal { version, .. } | Event::Enter { version, .. } => version as usize,
+                Event::Leave => 0,
+            })
+            .sum();
+        assert_eq!(versions, 3 + 300 * (1 + 300 * 2));
+    }
+}
@@ -1,31 +1,48 @@
 use std::io::BufRead;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use target::Target;
-use util::gauss_sum;
+use util::{gauss_sum, Solution};
 
-mod target;
+pub mod simulation;
+pub mod target;
+pub mod trajectory;
 
 type Input = Target;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    Ok(reader.lines().next().unwrap().unwrap().parse()?)
-}
+pub struct Day17;
 
-pub fn part1(values: &Input) -> usize {
-    // With `y` always `< 0` and an initial `y` velocity of `n`, the probe will rise to the gaussian
-    // sum of `n`, then visit the same height levels on the way back down. Once it has reached `y =
-    // 0`, its velocity will be `-n - 1`. So setting n as follows will hit the furthest vertical
-    // point in the step after returning to `y = 0`.
-    let initial_velocity: usize = (-(values.y.start() + 1)).try_into().unwrap();
-    gauss_sum(initial_velocity)
-}
+impl Solution for Day17 {
+    const DAY: u8 = 17;
 
-pub fn part2(values: &Input) -> usize {
-    let count_by_steps: Vec<usize> = vec![(values.x.end() - values.x.start()).try_into().unwrap()];
-    for _ in (1..*values.x.start()).into_iter().rev() {}
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        let target: Target = reader
+            .lines()
+            .next()
+            .ok_or_else(|| anyhow!("expected input"))??
+            .parse()?;
+        if target.y.contains(&0) {
+            bail!("targets at the launch height can be hit by infinitely many velocities");
+        }
+        Ok(target)
+    }
 
-    todo!("{:?}", count_by_steps)
+    fn part1(values: &Self::Input) -> usize {
+        values
+            .hits()
+            .into_iter()
+            .map(|velocity| gauss_sum(velocity.1.max(0) as usize))
+            .max()
+            .unwrap()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        values.hits().len()
+    }
 }
 
 #[cfg(test)]
@@ -37,17 +54,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day17::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 45);
+        assert_eq!(Day17::part1(&input()), 45);
     }
 
     #[test]
-    #[ignore = "unimplemented"]
     fn test2() {
-        assert_eq!(part2(&input()), 112);
+        assert_eq!(Day17::part2(&input()), 112);
     }
 }
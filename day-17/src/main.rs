@@ -1 +1 @@
-util::main!(day_17);
+util::main!(day_17::Day17);
@@ -0,0 +1,115 @@
+use crate::{
+    target::Target,
+    trajectory::{Pos, Velocity},
+};
+
+/// The full flight of a probe, including the steps after it entered the target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+    pub velocity: Velocity,
+    /// The position after each step, starting with the launch position at step 0.
+    pub positions: Vec<Pos>,
+    /// The first step after which the probe was inside the target.
+    pub entered: Option<usize>,
+}
+
+impl Target {
+    /// Follows a probe launched with `velocity` until it can no longer reach the target.
+    pub fn simulate(&self, velocity: Velocity) -> Simulation {
+        let positions: Vec<_> = std::iter::once(Pos(0, 0))
+            .chain(self.flight(velocity).map(|probe| probe.pos))
+            .collect();
+        let entered = positions.iter().position(|&pos| self.contains(pos));
+
+        Simulation {
+            velocity,
+            positions,
+            entered,
+        }
+    }
+}
+
+impl Simulation {
+    /// Draws the flight up to the step that entered the target, in the style of the puzzle text:
+    /// `S` is the launch position, `#` the probe after each step and `T` the target area.
+    pub fn render(&self, target: &Target) -> String {
+        let end = self.entered.map_or(self.positions.len(), |step| step + 1);
+        let positions = &self.positions[..end];
+
+        let x_min = positions.iter().map(|pos| pos.0).min().unwrap();
+        let x_max = positions.iter().map(|pos| pos.0).max().unwrap();
+        let y_min = positions.iter().map(|pos| pos.1).min().unwrap();
+        let y_max = positions.iter().map(|pos| pos.1).max().unwrap();
+        let x_range = x_min.min(*target.x.start())..=x_max.max(*target.x.end());
+        let y_range = y_min.min(*target.y.start())..=y_max.max(*target.y.end());
+
+        y_range
+            .rev()
+            .map(|y| {
+                x_range
+                    .clone()
+                    .map(|x| {
+                        let pos = Pos(x, y);
+                        if pos == positions[0] {
+                            'S'
+                        } else if positions.contains(&pos) {
+                            '#'
+                        } else if target.contains(pos) {
+                            'T'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn target() -> Target {
+        Target {
+            x: 20..=30,
+            y: -10..=-5,
+        }
+    }
+
+    #[test]
+    fn simulate() {
+        let simulation = target().simulate(Velocity(6, 9));
+        assert_eq!(simulation.entered, Some(20));
+        assert_eq!(simulation.positions[20], Pos(21, -10));
+        assert!(simulation.positions.len() > 21);
+
+        let simulation = target().simulate(Velocity(17, -4));
+        assert_eq!(simulation.entered, None);
+        assert_eq!(simulation.positions, [Pos(0, 0), Pos(17, -4), Pos(33, -9)]);
+    }
+
+    #[test]
+    fn render() {
+        let target = target();
+        assert_eq!(
+            target.simulate(Velocity(7, 2)).render(&target),
+            "\
+.............#....#............
+.......#..............#........
+...............................
+S........................#.....
+...............................
+...............................
+...........................#...
+...............................
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTT#TT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT
+....................TTTTTTTTTTT"
+        );
+    }
+}
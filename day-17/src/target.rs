@@ -21,7 +21,7 @@ mod parse {
 
     use super::Target;
 
-    fn number(input: &str) -> Result<i32> {
+    fn number(input: &str) -> Result<'_, i32> {
         pair(opt(tag("-")), digit1)
             .map(|(sign, abs): (Option<&str>, &str)| if sign.is_some() { -1 } else { 1 } * abs.parse::<i32>().unwrap())
             .parse(input)
@@ -36,7 +36,7 @@ mod parse {
         }
     }
 
-    pub fn parse(input: &str) -> Result<Target> {
+    pub fn parse(input: &str) -> Result<'_, Target> {
         preceded(
             pair(tag("target area:"), multispace1),
             separated_pair(range("x"), pair(tag(","), multispace0), range("y")),
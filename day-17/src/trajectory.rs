@@ -0,0 +1,151 @@
+use crate::target::Target;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pos(pub i32, pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Velocity(pub i32, pub i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Probe {
+    pub pos: Pos,
+    pub velocity: Velocity,
+}
+
+impl Probe {
+    pub fn launch(velocity: Velocity) -> Self {
+        Self {
+            pos: Pos(0, 0),
+            velocity,
+        }
+    }
+
+    pub fn step(&mut self) {
+        let Velocity(dx, dy) = self.velocity;
+        self.pos = Pos(self.pos.0 + dx, self.pos.1 + dy);
+        self.velocity = Velocity(dx - dx.signum(), dy - 1);
+    }
+}
+
+impl Target {
+    pub fn contains(&self, Pos(x, y): Pos) -> bool {
+        self.x.contains(&x) && self.y.contains(&y)
+    }
+
+    /// Whether `probe` has no chance of entering the target anymore.
+    pub fn out_of_reach(&self, probe: &Probe) -> bool {
+        let Pos(x, y) = probe.pos;
+        let Velocity(dx, dy) = probe.velocity;
+
+        let past_x = match dx.signum() {
+            1 => x > *self.x.end(),
+            -1 => x < *self.x.start(),
+            _ => !self.x.contains(&x),
+        };
+        let past_y = dy <= 0 && y < *self.y.start();
+
+        past_x || past_y
+    }
+
+    /// The states of a probe launched with `velocity` after each step, up to the first one from
+    /// which it can no longer reach the target.
+    pub fn flight(&self, velocity: Velocity) -> impl Iterator<Item = Probe> + '_ {
+        let mut probe = Probe::launch(velocity);
+        std::iter::from_fn(move || {
+            if self.out_of_reach(&probe) {
+                return None;
+            }
+            probe.step();
+            Some(probe)
+        })
+    }
+
+    /// The positions of a probe launched with `velocity`, up to the first one inside the target.
+    /// Returns `None` if the probe misses.
+    pub fn trajectory(&self, velocity: Velocity) -> Option<Vec<Pos>> {
+        let mut result = Vec::new();
+        for probe in self.flight(velocity) {
+            result.push(probe.pos);
+            if self.contains(probe.pos) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// All initial velocities which hit the target, ordered by `x` then `y`.
+    ///
+    /// The first step moves the probe by its full velocity, so velocities larger than the
+    /// distance to the far side of the target overshoot in `x`. In `y`, probes launched upwards
+    /// return to the launch height with their initial speed reversed. So unless the target spans
+    /// the launch height, neither speed can exceed the distance to the far side of the target.
+    pub fn hits(&self) -> Vec<Velocity> {
+        let x_range = (*self.x.start()).min(0)..=(*self.x.end()).max(0);
+        let y_bound = self.y.start().abs().max(self.y.end().abs());
+
+        x_range
+            .flat_map(|x| (-y_bound..=y_bound).map(move |y| Velocity(x, y)))
+            .filter(|&velocity| self.trajectory(velocity).is_some())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn target(x: (i32, i32), y: (i32, i32)) -> Target {
+        Target {
+            x: x.0..=x.1,
+            y: y.0..=y.1,
+        }
+    }
+
+    #[test]
+    fn trajectory() {
+        let target = target((20, 30), (-10, -5));
+        assert_eq!(
+            target.trajectory(Velocity(7, 2)),
+            Some(
+                [
+                    (7, 2),
+                    (13, 3),
+                    (18, 3),
+                    (22, 2),
+                    (25, 0),
+                    (27, -3),
+                    (28, -7)
+                ]
+                .map(|(x, y)| Pos(x, y))
+                .to_vec()
+            )
+        );
+        assert!(target.trajectory(Velocity(6, 3)).is_some());
+        assert!(target.trajectory(Velocity(9, 0)).is_some());
+        assert_eq!(target.trajectory(Velocity(17, -4)), None);
+    }
+
+    #[test]
+    fn mirrored() {
+        assert_eq!(target((20, 30), (-10, -5)).hits().len(), 112);
+        assert_eq!(target((-30, -20), (-10, -5)).hits().len(), 112);
+    }
+
+    #[test]
+    fn above() {
+        let target = target((-6, 12), (8, 15));
+        let brute_force: Vec<_> = (-50..=50)
+            .flat_map(|x| (-50..=50).map(move |y| Velocity(x, y)))
+            .filter(|&velocity| {
+                let mut probe = Probe::launch(velocity);
+                (0..200).any(|_| {
+                    probe.step();
+                    target.contains(probe.pos)
+                })
+            })
+            .collect();
+
+        assert!(!brute_force.is_empty());
+        assert_eq!(target.hits(), brute_force);
+    }
+}
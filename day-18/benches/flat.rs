@@ -0,0 +1,2 @@
+const INPUT: &str = include_str!("../input.txt");
+util::bench!(day_18::Day18<day_18::flat::FlatSum>, "flat");
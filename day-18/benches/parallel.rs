@@ -0,0 +1,30 @@
+use std::io::Cursor;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_18::{
+    flat::FlatSum, largest_magnitude, par_largest_magnitude, snailfish_sum::SnailfishSum, Day18,
+    Snailfish,
+};
+use util::Solution;
+
+const INPUT: &str = include_str!("../input.txt");
+
+fn compare<N: Snailfish>(c: &mut Criterion, name: &str) {
+    let values = Day18::<N>::read_input(Cursor::new(INPUT)).unwrap();
+
+    let mut group = c.benchmark_group(format!("Day 18 Part 2 ({})", name));
+    group.bench_function("Sequential", |b| {
+        b.iter(|| largest_magnitude(black_box(&values)))
+    });
+    group.bench_function("Parallel", |b| {
+        b.iter(|| par_largest_magnitude(black_box(&values)))
+    });
+}
+
+pub fn benchmark(c: &mut Criterion) {
+    compare::<SnailfishSum>(c, "tree");
+    compare::<FlatSum>(c, "flat");
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,255 @@
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    iter::Peekable,
+    ops::Add,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{snailfish_sum::SnailfishSum, Snailfish};
+
+/// Two reduced numbers have at most 16 literals each, and reducing their sum never exceeds the
+/// literals it started with.
+const CAPACITY: usize = 32;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Literal {
+    value: u8,
+    depth: u8,
+}
+
+/// A snailfish number stored as its literals from left to right, each with its nesting depth.
+///
+/// Only reduced numbers can be parsed, so that sums fit into a fixed size array.
+#[derive(Clone, Copy)]
+pub struct FlatSum {
+    len: usize,
+    literals: [Literal; CAPACITY],
+}
+
+impl FlatSum {
+    fn literals(&self) -> &[Literal] {
+        &self.literals[..self.len]
+    }
+
+    fn insert(&mut self, index: usize, literal: Literal) {
+        self.literals.copy_within(index..self.len, index + 1);
+        self.literals[index] = literal;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.literals.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+    }
+
+    /// Explodes the pair whose left literal is at `index`.
+    fn explode(&mut self, index: usize) {
+        let [left, right] = [self.literals[index], self.literals[index + 1]];
+        if let Some(prev) = index.checked_sub(1) {
+            self.literals[prev].value += left.value;
+        }
+        if index + 2 < self.len {
+            self.literals[index + 2].value += right.value;
+        }
+
+        self.literals[index] = Literal {
+            value: 0,
+            depth: left.depth - 1,
+        };
+        self.remove(index + 1);
+    }
+
+    fn reduce(&mut self) {
+        // Exploding never nests literals deeper, so all pairs of the sum can be exploded in one
+        // pass before splitting.
+        let mut i = 0;
+        while i < self.len {
+            if self.literals[i].depth > 4 {
+                self.explode(i);
+            }
+            i += 1;
+        }
+
+        while let Some(i) = self.literals().iter().position(|l| l.value >= 10) {
+            let Literal { value, depth } = self.literals[i];
+            let half = value / 2;
+            self.literals[i] = Literal {
+                value: half,
+                depth: depth + 1,
+            };
+            self.insert(
+                i + 1,
+                Literal {
+                    value: value - half,
+                    depth: depth + 1,
+                },
+            );
+
+            if depth == 4 {
+                self.explode(i);
+            }
+        }
+    }
+
+    fn fmt_pair<'a>(
+        f: &mut Formatter<'_>,
+        literals: &mut Peekable<impl Iterator<Item = &'a Literal>>,
+        depth: u8,
+    ) -> fmt::Result {
+        match literals.peek() {
+            Some(literal) if literal.depth == depth => {
+                write!(f, "{}", literal.value)?;
+                literals.next();
+                Ok(())
+            }
+            _ => {
+                write!(f, "[")?;
+                Self::fmt_pair(f, literals, depth + 1)?;
+                write!(f, ",")?;
+                Self::fmt_pair(f, literals, depth + 1)?;
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Snailfish for FlatSum {
+    fn magnitude(&self) -> usize {
+        let mut stack: Vec<(usize, u8)> = Vec::with_capacity(CAPACITY);
+        for literal in self.literals() {
+            let mut top = (literal.value as usize, literal.depth);
+            while let Some(&(left, depth)) = stack.last() {
+                if depth != top.1 {
+                    break;
+                }
+                stack.pop();
+                top = (3 * left + 2 * top.0, depth - 1);
+            }
+            stack.push(top);
+        }
+        stack[0].0
+    }
+}
+
+impl Add for FlatSum {
+    type Output = FlatSum;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.literals[self.len..self.len + rhs.len].copy_from_slice(rhs.literals());
+        self.len += rhs.len;
+        for literal in &mut self.literals[..self.len] {
+            literal.depth += 1;
+        }
+
+        self.reduce();
+        self
+    }
+}
+
+impl PartialEq for FlatSum {
+    fn eq(&self, other: &Self) -> bool {
+        self.literals() == other.literals()
+    }
+}
+
+impl Eq for FlatSum {}
+
+impl TryFrom<&SnailfishSum> for FlatSum {
+    type Error = anyhow::Error;
+
+    fn try_from(sum: &SnailfishSum) -> Result<Self> {
+        let mut result = FlatSum {
+            len: 0,
+            literals: Default::default(),
+        };
+
+        for (value, depth) in sum.literals() {
+            if depth > 4 {
+                return Err(anyhow!("{} is not reduced", sum));
+            }
+            result.literals[result.len] = Literal { value, depth };
+            result.len += 1;
+        }
+
+        Ok(result)
+    }
+}
+
+impl FromStr for FlatSum {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_from(&s.parse::<SnailfishSum>()?)
+    }
+}
+
+impl Display for FlatSum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Self::fmt_pair(f, &mut self.literals().iter().peekable(), 0)
+    }
+}
+
+impl Debug for FlatSum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(input: &str) -> FlatSum {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn display() {
+        for line in include_str!("test_input.txt").lines() {
+            assert_eq!(parse(line).to_string(), line);
+        }
+        assert!("[[[[[9,8],1],2],3],4]".parse::<FlatSum>().is_err());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            parse("[[[[4,3],4],4],[7,[[8,4],9]]]") + parse("[1,1]"),
+            parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+        );
+
+        assert_eq!(
+            parse("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]")
+                + parse("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]"),
+            parse("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]")
+        );
+    }
+
+    #[test]
+    fn magnitude() {
+        for (sum, magnitude) in [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(parse(sum).magnitude(), magnitude);
+        }
+    }
+
+    #[test]
+    fn matches_tree() {
+        let lines: Vec<_> = include_str!("test_input.txt").lines().collect();
+        for a in &lines {
+            for b in &lines {
+                let tree = a.parse::<SnailfishSum>().unwrap() + b.parse().unwrap();
+                assert_eq!((parse(a) + parse(b)).to_string(), tree.to_string());
+            }
+        }
+    }
+}
@@ -1,24 +1,22 @@
-use std::{io::BufRead, ops::Add};
+use std::{io::BufRead, marker::PhantomData, ops::Add, str::FromStr};
 
 use anyhow::Result;
 use itertools::Itertools;
-use snailfish_sum::{SnailfishSum, SnailfishSums};
+use snailfish_sum::SnailfishSum;
+use util::Solution;
 
-mod snailfish_sum;
+pub mod flat;
+pub mod snailfish_sum;
 
-type Input = Vec<SnailfishSum>;
-
-pub fn read_input(mut reader: impl BufRead) -> Result<Input> {
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
-    Ok(buf.parse::<SnailfishSums>()?.0)
-}
-
-pub fn part1(values: &[SnailfishSum]) -> usize {
-    values.iter().cloned().reduce(Add::add).unwrap().magnitude()
+/// Representation of snailfish numbers the solution can work with.
+pub trait Snailfish:
+    Clone + PartialEq + Send + Sync + Add<Output = Self> + FromStr<Err = anyhow::Error>
+{
+    fn magnitude(&self) -> usize;
 }
 
-pub fn part2(values: &[SnailfishSum]) -> usize {
+/// The largest magnitude of adding two different numbers of `values`.
+pub fn largest_magnitude<N: Snailfish>(values: &[N]) -> usize {
     values
         .iter()
         .cartesian_product(values)
@@ -28,6 +26,53 @@ pub fn part2(values: &[SnailfishSum]) -> usize {
         .unwrap()
 }
 
+/// Same as [`largest_magnitude`], but with the left-hand sides spread across threads. The result
+/// does not depend on scheduling, as only the maximum is kept.
+#[cfg(feature = "rayon")]
+pub fn par_largest_magnitude<N: Snailfish>(values: &[N]) -> usize {
+    use rayon::prelude::*;
+
+    values
+        .par_iter()
+        .filter_map(|a| {
+            values
+                .iter()
+                .filter(|&b| a != b)
+                .map(|b| (a.clone() + b.clone()).magnitude())
+                .max()
+        })
+        .max()
+        .unwrap()
+}
+
+/// Uses the boxed tree of [`SnailfishSum`] unless another representation is selected, e.g.
+/// `Day18<flat::FlatSum>`.
+pub struct Day18<N = SnailfishSum>(PhantomData<N>);
+
+impl<N: Snailfish> Solution for Day18<N> {
+    const DAY: u8 = 18;
+
+    type Input = Vec<N>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|line| line?.parse()).collect()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        values.iter().cloned().reduce(Add::add).unwrap().magnitude()
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        #[cfg(feature = "rayon")]
+        let result = par_largest_magnitude(values);
+        #[cfg(not(feature = "rayon"))]
+        let result = largest_magnitude(values);
+        result
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -36,17 +81,31 @@ mod test {
 
     const INPUT: &str = include_str!("test_input.txt");
 
-    fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+    fn input() -> Vec<SnailfishSum> {
+        Day18::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 4140);
+        assert_eq!(Day18::part1(&input()), 4140);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 3993);
+        assert_eq!(Day18::part2(&input()), 3993);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel() {
+        let values = input();
+        assert_eq!(par_largest_magnitude(&values), largest_magnitude(&values));
+    }
+
+    #[test]
+    fn flat() {
+        let input = Day18::<flat::FlatSum>::read_input(Cursor::new(INPUT)).unwrap();
+        assert_eq!(Day18::part1(&input), 4140);
+        assert_eq!(Day18::part2(&input), 3993);
     }
 }
@@ -1 +1 @@
-util::main!(day_18);
+util::main!(day_18::Day18);
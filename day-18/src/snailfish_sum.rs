@@ -1,13 +1,26 @@
-use std::{ops::Add, str::FromStr};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::Add,
+    str::FromStr,
+};
 
-use nom::{error::convert_error, Finish};
+use nom::{combinator::all_consuming, error::convert_error, Finish};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use crate::Snailfish;
+
+#[derive(Clone, PartialEq, Eq)]
 pub enum SnailfishSum {
     Literal(u8),
     Pair(Box<(SnailfishSum, SnailfishSum)>),
 }
 
+/// A single step of the reduction of a sum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Explode,
+    Split,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Side {
     Left,
@@ -81,15 +94,69 @@ impl SnailfishSum {
         }
     }
 
-    fn reduce_step(&mut self) -> bool {
-        self.try_explode(0).is_some() || self.try_split()
+    fn reduce_step(&mut self) -> Option<Action> {
+        if self.try_explode(0).is_some() {
+            Some(Action::Explode)
+        } else if self.try_split() {
+            Some(Action::Split)
+        } else {
+            None
+        }
     }
 
     fn reduce(&mut self) {
-        while self.reduce_step() {}
+        self.reduce_with(|_, _| {});
+    }
+
+    /// Reduces the sum, calling `trace` with every action and the sum it resulted in.
+    pub fn reduce_with(&mut self, mut trace: impl FnMut(Action, &Self)) {
+        while let Some(action) = self.reduce_step() {
+            trace(action, self);
+        }
+    }
+
+    /// The literals from left to right, together with their nesting depth.
+    pub fn literals(&self) -> Literals<'_> {
+        Literals(vec![(self, 0)])
+    }
+}
+
+pub struct Literals<'a>(Vec<(&'a SnailfishSum, u8)>);
+
+impl Iterator for Literals<'_> {
+    type Item = (u8, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.0.pop()? {
+                (&SnailfishSum::Literal(n), depth) => return Some((n, depth)),
+                (SnailfishSum::Pair(pair), depth) => {
+                    self.0.push((&pair.1, depth + 1));
+                    self.0.push((&pair.0, depth + 1));
+                }
+            }
+        }
+    }
+}
+
+impl Display for SnailfishSum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SnailfishSum::Literal(n) => write!(f, "{}", n),
+            SnailfishSum::Pair(pair) => write!(f, "[{},{}]", pair.0, pair.1),
+        }
+    }
+}
+
+// The derived output of nested boxes is unreadable for all but the smallest sums
+impl Debug for SnailfishSum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
     }
+}
 
-    pub fn magnitude(&self) -> usize {
+impl Snailfish for SnailfishSum {
+    fn magnitude(&self) -> usize {
         match self {
             &SnailfishSum::Literal(n) => n as usize,
             SnailfishSum::Pair(pair) => {
@@ -111,12 +178,10 @@ impl Add for SnailfishSum {
 }
 
 mod parse {
-    pub use nom::error::VerboseError as Error;
     use nom::{
         branch::alt,
         character::complete::{char, u8},
         error::VerboseError,
-        multi::separated_list1,
         sequence::{delimited, separated_pair},
         IResult, Parser,
     };
@@ -125,11 +190,11 @@ mod parse {
 
     type Result<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
 
-    fn literal(input: &str) -> Result<SnailfishSum> {
+    fn literal(input: &str) -> Result<'_, SnailfishSum> {
         u8.map(SnailfishSum::Literal).parse(input)
     }
 
-    fn pair(input: &str) -> Result<SnailfishSum> {
+    fn pair(input: &str) -> Result<'_, SnailfishSum> {
         delimited(
             char('['),
             separated_pair(snailfish_sum, char(','), snailfish_sum),
@@ -139,25 +204,19 @@ mod parse {
         .parse(input)
     }
 
-    pub fn snailfish_sum(input: &str) -> Result<SnailfishSum> {
+    pub fn snailfish_sum(input: &str) -> Result<'_, SnailfishSum> {
         alt((literal, pair))(input)
     }
-
-    pub fn parse(input: &str) -> Result<Vec<SnailfishSum>> {
-        separated_list1(char('\n'), snailfish_sum)(input)
-    }
 }
 
-pub struct SnailfishSums(pub Vec<SnailfishSum>);
-
-impl FromStr for SnailfishSums {
+impl FromStr for SnailfishSum {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        parse::parse(s)
+        all_consuming(parse::snailfish_sum)(s)
             .finish()
             .map_err(|e| anyhow::anyhow!(convert_error(s, e)))
-            .map(|(_, res)| SnailfishSums(res))
+            .map(|(_, res)| res)
     }
 }
 
@@ -225,6 +284,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn display() {
+        for line in include_str!("test_input.txt").lines() {
+            assert_eq!(parse(line).to_string(), line);
+        }
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            parse("[[1,[2,3]],4]").literals().collect::<Vec<_>>(),
+            [(1, 2), (2, 3), (3, 3), (4, 1)]
+        );
+        assert_eq!(parse("7").literals().collect::<Vec<_>>(), [(7, 0)]);
+    }
+
+    #[test]
+    fn trace() {
+        let mut sum = parse("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        let mut steps = Vec::new();
+        sum.reduce_with(|action, sum| steps.push((action, sum.to_string())));
+
+        assert_eq!(
+            steps,
+            [
+                (Action::Explode, "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]"),
+                (Action::Explode, "[[[[0,7],4],[15,[0,13]]],[1,1]]"),
+                (Action::Split, "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]"),
+                (Action::Split, "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]"),
+                (Action::Explode, "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"),
+            ]
+            .map(|(action, sum)| (action, sum.to_owned()))
+        );
+    }
+
     #[test]
     fn problematic_reduction() {
         let mut sum =
@@ -253,11 +347,11 @@ mod test {
             "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[0,[5,5]]]]]",
             "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
         ] {
-            assert!(sum.reduce_step());
+            assert!(sum.reduce_step().is_some());
             assert_eq!(sum, parse(step));
         }
 
-        assert!(!sum.reduce_step());
+        assert_eq!(sum.reduce_step(), None);
     }
 
     #[test]
@@ -0,0 +1,9 @@
+const INPUT: &str = include_str!("../input.txt");
+const ANSWERS: &str = include_str!("../answers.txt");
+util::check!(day_18::Day18);
+
+mod flat {
+    use super::*;
+
+    util::check!(day_18::Day18<day_18::flat::FlatSum>);
+}
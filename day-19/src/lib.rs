@@ -0,0 +1,225 @@
+use std::{
+    cell::OnceCell,
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use anyhow::Result;
+use itertools::Itertools;
+use scanner::{Point, Rotation, Scanner};
+use thiserror::Error;
+use util::{Fallible, Solution};
+
+pub mod scanner;
+
+const MIN_OVERLAP: usize = 12;
+const MIN_SHARED_DISTANCES: usize = MIN_OVERLAP * (MIN_OVERLAP - 1) / 2;
+
+/// The beacons and scanners of all reports, relative to the first scanner.
+#[derive(Debug)]
+pub struct Map {
+    pub beacons: HashSet<Point>,
+    pub scanners: Vec<Point>,
+}
+
+/// A scanner none of whose beacons can be matched up with those of the other scanners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("scanner {0} does not overlap with the others")]
+pub struct Disconnected(pub usize);
+
+/// The scanner reports as read, aligned into a [`Map`] the first time a part asks for it.
+///
+/// Aligning is most of the work of this day, so it is left to the parts rather than done while
+/// parsing, and shared between them.
+pub struct Reports {
+    scanners: Vec<Scanner>,
+    map: OnceCell<Result<Map, Disconnected>>,
+}
+
+impl Reports {
+    pub fn new(scanners: Vec<Scanner>) -> Self {
+        Self {
+            scanners,
+            map: OnceCell::new(),
+        }
+    }
+
+    pub fn scanners(&self) -> &[Scanner] {
+        &self.scanners
+    }
+
+    pub fn map(&self) -> Result<&Map, Disconnected> {
+        self.map
+            .get_or_init(|| build_map(&self.scanners))
+            .as_ref()
+            .map_err(|&e| e)
+    }
+}
+
+type Input = Reports;
+
+/// Number of values two ascending lists have in common.
+fn shared(a: &[i32], b: &[i32]) -> usize {
+    let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+    let mut result = 0;
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        match x.cmp(y) {
+            Ordering::Less => {
+                a.next();
+            }
+            Ordering::Greater => {
+                b.next();
+            }
+            Ordering::Equal => {
+                result += 1;
+                a.next();
+                b.next();
+            }
+        }
+    }
+    result
+}
+
+/// Finds the position of `scanner` and its beacons relative to `reference`, if at least
+/// [`MIN_OVERLAP`] of its beacons match up with the reference beacons in one of the rotations.
+fn align(reference: &[Point], scanner: &Scanner) -> Option<(Point, Vec<Point>)> {
+    Rotation::all().find_map(|rotation| {
+        let rotated: Vec<_> = scanner.beacons.iter().map(|&b| rotation.apply(b)).collect();
+
+        let mut offsets = HashMap::new();
+        let offset = reference
+            .iter()
+            .cartesian_product(&rotated)
+            .map(|(&a, &b)| a - b)
+            .find(|&offset| {
+                let count = offsets.entry(offset).or_insert(0);
+                *count += 1;
+                *count >= MIN_OVERLAP
+            })?;
+
+        Some((offset, rotated.into_iter().map(|b| b + offset).collect()))
+    })
+}
+
+fn build_map(scanners: &[Scanner]) -> Result<Map, Disconnected> {
+    let fingerprints: Vec<_> = scanners.iter().map(Scanner::fingerprint).collect();
+
+    let mut aligned: Vec<Option<(Point, Vec<Point>)>> = vec![None; scanners.len()];
+    aligned[0] = Some((Point::default(), scanners[0].beacons.clone()));
+    let mut todo = vec![0];
+
+    while let Some(i) = todo.pop() {
+        for j in 0..scanners.len() {
+            if aligned[j].is_some()
+                || shared(&fingerprints[i], &fingerprints[j]) < MIN_SHARED_DISTANCES
+            {
+                continue;
+            }
+
+            if let Some(result) = align(&aligned[i].as_ref().unwrap().1, &scanners[j]) {
+                aligned[j] = Some(result);
+                todo.push(j);
+            }
+        }
+    }
+
+    let mut map = Map {
+        beacons: HashSet::new(),
+        scanners: Vec::new(),
+    };
+    for (i, scanner) in aligned.into_iter().enumerate() {
+        let (position, beacons) = scanner.ok_or(Disconnected(i))?;
+        map.scanners.push(position);
+        map.beacons.extend(beacons);
+    }
+
+    Ok(map)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Input;
+    type Answer1 = Fallible<usize, Disconnected>;
+    type Answer2 = Fallible<i32, Disconnected>;
+
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        Ok(Reports::new(scanner::parse(&buf)?))
+    }
+
+    fn part1(values: &Self::Input) -> Self::Answer1 {
+        values.map().map(|map| map.beacons.len()).into()
+    }
+
+    fn part2(values: &Self::Input) -> Self::Answer2 {
+        let distances = |map: &Map| {
+            map.scanners
+                .iter()
+                .tuple_combinations()
+                .map(|(&a, &b)| a.manhattan(b))
+                .max()
+                .unwrap_or_default()
+        };
+        values.map().map(distances).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("test_input.txt");
+
+    fn input() -> Input {
+        Day19::read_input(Cursor::new(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day19::part1(&input()), Fallible(Ok(79)));
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day19::part2(&input()), Fallible(Ok(3621)));
+    }
+
+    #[test]
+    fn scanners() {
+        let input = input();
+        let scanners: HashSet<_> = input.map().unwrap().scanners.iter().copied().collect();
+        let expected = [
+            [0, 0, 0],
+            [68, -1246, -43],
+            [1105, -1205, 1229],
+            [-92, -2380, -20],
+            [-20, -1133, 1061],
+        ];
+        assert_eq!(scanners, expected.into_iter().map(Point).collect());
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day19::read_input(Cursor::new("")).is_err());
+        assert!(Day19::read_input(Cursor::new("\n\n")).is_err());
+    }
+
+    #[test]
+    fn disconnected() {
+        let (first, _) = INPUT.split_once("\n\n").unwrap();
+        let input = format!("{}\n\n--- scanner 1 ---\n1,2,3\n", first);
+        let input = Day19::read_input(Cursor::new(input)).unwrap();
+        assert_eq!(input.map().unwrap_err(), Disconnected(1));
+        assert_eq!(
+            Day19::part1(&input).to_string(),
+            "error: scanner 1 does not overlap with the others"
+        );
+    }
+}
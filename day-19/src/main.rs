@@ -0,0 +1 @@
+util::main!(day_19::Day19);
@@ -0,0 +1,170 @@
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Result};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point(pub [i32; 3]);
+
+impl Point {
+    pub fn manhattan(self, other: Self) -> i32 {
+        let Point(d) = self - other;
+        d.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn squared_length(self) -> i32 {
+        self.0.iter().map(|c| c * c).sum()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point([0, 1, 2].map(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point([0, 1, 2].map(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl FromStr for Point {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()?;
+        Ok(Point(coords.try_into().map_err(|_| {
+            anyhow!("expected three coordinates, found {:?}", s)
+        })?))
+    }
+}
+
+/// One of the 24 ways a scanner can be facing, as a permutation of the axes with signs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i32; 3],
+}
+
+impl Rotation {
+    pub fn all() -> impl Iterator<Item = Rotation> {
+        const PERMUTATIONS: [([usize; 3], i32); 6] = [
+            ([0, 1, 2], 1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([0, 2, 1], -1),
+            ([2, 1, 0], -1),
+            ([1, 0, 2], -1),
+        ];
+
+        PERMUTATIONS.into_iter().flat_map(|(axes, parity)| {
+            (0..8)
+                .map(|bits| [0, 1, 2].map(|i| if bits & (1 << i) == 0 { 1 } else { -1 }))
+                // Keep the determinant positive to exclude mirror images
+                .filter(move |signs: &[i32; 3]| signs.iter().product::<i32>() == parity)
+                .map(move |signs| Rotation { axes, signs })
+        })
+    }
+
+    pub fn apply(&self, Point(p): Point) -> Point {
+        Point([0, 1, 2].map(|i| p[self.axes[i]] * self.signs[i]))
+    }
+}
+
+/// The beacons detected by one scanner, relative to its own position and facing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    pub beacons: Vec<Point>,
+}
+
+impl Scanner {
+    /// The squared distances between all pairs of beacons, in ascending order. They do not depend
+    /// on position or facing, so scanners sharing 12 beacons share at least 66 of them.
+    pub fn fingerprint(&self) -> Vec<i32> {
+        let mut result: Vec<_> = self
+            .beacons
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &a)| {
+                self.beacons[i + 1..]
+                    .iter()
+                    .map(move |&b| (a - b).squared_length())
+            })
+            .collect();
+        result.sort_unstable();
+        result
+    }
+}
+
+/// Parses the reports of all scanners, each starting with a `--- scanner N ---` header.
+///
+/// Blank lines and `\r\n` line endings are accepted anywhere.
+pub fn parse(s: &str) -> Result<Vec<Scanner>> {
+    let mut scanners: Vec<Scanner> = vec![];
+    for line in s.lines().map(str::trim_end) {
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("--- scanner") {
+            scanners.push(Scanner { beacons: vec![] });
+            continue;
+        }
+
+        let scanner = scanners
+            .last_mut()
+            .ok_or_else(|| anyhow!("expected scanner header, found {:?}", line))?;
+        scanner.beacons.push(line.parse()?);
+    }
+    if scanners.is_empty() {
+        bail!("no scanner reports found");
+    }
+    Ok(scanners)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let p = Point([1, 2, 3]);
+        let rotated: HashSet<_> = Rotation::all().map(|r| r.apply(p)).collect();
+        assert_eq!(rotated.len(), 24);
+        assert!(rotated.contains(&Point([-2, 1, 3])));
+        assert!(!rotated.contains(&Point([-1, 2, 3])));
+    }
+
+    #[test]
+    fn reports() {
+        let scanners =
+            parse("--- scanner 0 ---\r\n1,2,3\r\n\r\n--- scanner 1 ---\n4,5,6\n7,8,9\n\n").unwrap();
+        assert_eq!(scanners.len(), 2);
+        assert_eq!(scanners[0].beacons, [Point([1, 2, 3])]);
+        assert_eq!(scanners[1].beacons, [Point([4, 5, 6]), Point([7, 8, 9])]);
+
+        assert!(parse("1,2,3\n").is_err());
+        assert!(parse("--- scanner 0 ---\n1,2\n").is_err());
+    }
+
+    #[test]
+    fn point() {
+        assert_eq!("1,-2,3".parse::<Point>().unwrap(), Point([1, -2, 3]));
+        assert!("1,2".parse::<Point>().is_err());
+        assert_eq!(
+            Point([1105, -1205, 1229]).manhattan(Point([-92, -2380, -20])),
+            3621
+        );
+    }
+}
@@ -2,28 +2,12 @@ use std::io::BufRead;
 
 use anyhow::Result;
 use pixel::{Mapping, Pixels};
+use util::Solution;
 
 mod pixel;
 
 pub struct Input(Mapping, Pixels);
 
-pub fn read_input(mut reader: impl BufRead) -> Result<Input> {
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf)?;
-
-    let (mapping, grid) = buf.split_once("\n\n").unwrap();
-
-    Ok(Input(
-        mapping
-            .chars()
-            .map(|c| c.try_into().unwrap())
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap(),
-        grid.parse()?,
-    ))
-}
-
 fn enhance(values: &Input, times: usize) -> usize {
     let mut grid = values.1.clone();
     for _ in 0..times {
@@ -32,12 +16,39 @@ fn enhance(values: &Input, times: usize) -> usize {
     grid.count_lights()
 }
 
-pub fn part1(values: &Input) -> usize {
-    enhance(values, 2)
-}
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
 
-pub fn part2(values: &Input) -> usize {
-    enhance(values, 50)
+        let (mapping, grid) = buf.split_once("\n\n").unwrap();
+
+        Ok(Input(
+            mapping
+                .chars()
+                .map(|c| c.try_into().unwrap())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            grid.parse()?,
+        ))
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        enhance(values, 2)
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        enhance(values, 50)
+    }
 }
 
 #[cfg(test)]
@@ -49,16 +60,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day20::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 35);
+        assert_eq!(Day20::part1(&input()), 35);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 3351);
+        assert_eq!(Day20::part2(&input()), 3351);
     }
 }
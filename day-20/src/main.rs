@@ -1 +1 @@
-util::main!(day_20);
+util::main!(day_20::Day20);
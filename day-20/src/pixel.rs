@@ -1,20 +1,15 @@
 use std::{fmt::Display, str::FromStr};
 
 use anyhow::anyhow;
-use util::grid::{Digit, Grid};
+use util::grid::{Digit, Grid, Point, Render, BLOCK};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Pixel {
+    #[default]
     Dark,
     Light,
 }
 
-impl Default for Pixel {
-    fn default() -> Self {
-        Pixel::Dark
-    }
-}
-
 impl TryFrom<char> for Pixel {
     type Error = anyhow::Error;
 
@@ -33,16 +28,12 @@ impl Digit for Pixel {
     }
 }
 
-impl Display for Pixel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Pixel::Dark => '.',
-                Pixel::Light => '#',
-            }
-        )
+impl Render for Pixel {
+    fn to_char(&self) -> char {
+        match self {
+            Pixel::Dark => '.',
+            Pixel::Light => '#',
+        }
     }
 }
 
@@ -52,21 +43,11 @@ pub type Mapping = [Pixel; 512];
 pub struct Pixels(Grid<Pixel>, Pixel);
 
 impl Pixels {
-    fn filter_index(&self, x: isize, y: isize, free_space: Pixel) -> usize {
+    fn filter_index(&self, pos: Point, free_space: Pixel) -> usize {
         let mut index = 0;
-        for (xoff, yoff) in [
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-            (-1, 0),
-            (0, 0),
-            (1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-        ] {
+        for offset in BLOCK {
             index = (index << 1)
-                | (*self.0.get(x + xoff, y + yoff).unwrap_or(&free_space) == Pixel::Light) as usize;
+                | (*self.0.get(pos + offset).unwrap_or(&free_space) == Pixel::Light) as usize;
         }
 
         index
@@ -76,10 +57,8 @@ impl Pixels {
         //assert!(mapping[0] == Pixel::Dark);
         let mut result = Grid::new(self.0.width() + 2, self.0.height() + 2);
 
-        for x in 0..result.width() {
-            for y in 0..result.height() {
-                *result.get_mut(x, y).unwrap() = mapping[self.filter_index(x - 1, y - 1, self.1)];
-            }
+        for (pos, pixel) in result.iter_mut() {
+            *pixel = mapping[self.filter_index(pos - Point::new(1, 1), self.1)];
         }
 
         let index = if self.1 == Pixel::Dark {
@@ -110,14 +89,7 @@ impl FromStr for Pixels {
 
 impl Display for Pixels {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.0.height() {
-            for x in 0..self.0.width() {
-                write!(f, "{}", self.0.get(x, y).unwrap())?;
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
+        self.0.fmt(f)
     }
 }
 
@@ -127,17 +99,26 @@ mod test {
 
     use std::io::Cursor;
 
-    use crate::{read_input, Input};
+    use util::Solution;
+
+    use crate::{Day20, Input};
 
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day20::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn kernel() {
         let input = input();
-        assert_eq!(input.1.filter_index(2, 2, Pixel::Dark), 34);
+        assert_eq!(input.1.filter_index(Point::new(2, 2), Pixel::Dark), 34);
+    }
+
+    #[test]
+    fn display() {
+        let input = input();
+        let image = INPUT.split_once("\n\n").unwrap().1;
+        assert_eq!(input.1.to_string(), image);
     }
 }
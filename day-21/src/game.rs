@@ -54,7 +54,7 @@ impl State {
 
     pub fn apply_roll(&mut self, roll: usize, winning_score: usize) -> Option<Player> {
         let score = self.players[self.current_player.idx()].apply_roll(roll);
-        let result = (score >= winning_score).then(|| self.current_player);
+        let result = (score >= winning_score).then_some(self.current_player);
         self.current_player.swap();
         result
     }
@@ -2,72 +2,83 @@ use std::{cmp::max, io::BufRead, mem::swap};
 
 use anyhow::Result;
 use game::State;
+use util::Solution;
 
 mod game;
 
 type Input = State;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    let mut lines = reader.lines();
+const SCORE_DISTRIBUTION: [(usize, usize); 7] =
+    [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
 
-    let a = lines.next().unwrap().unwrap();
-    let b = lines.next().unwrap().unwrap();
+pub struct Day21;
 
-    let (_, a) = a.split_once(':').unwrap();
-    let (_, b) = b.split_once(':').unwrap();
+impl Solution for Day21 {
+    const DAY: u8 = 21;
 
-    Ok(State::new(a.trim().parse()?, b.trim().parse()?))
-}
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part1(values: &Input) -> usize {
-    let mut state = values.clone();
-
-    let mut die = 1;
-    let mut roll_count = 0;
-    let mut roll = || {
-        let result = die;
-        roll_count += 1;
-        die = (die % 100) + 1;
-        result
-    };
-
-    let loosing_score = loop {
-        if let Some(mut player) = state.apply_roll(roll() + roll() + roll(), 1000) {
-            player.swap();
-            break state.score(player);
-        }
-    };
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        let mut lines = reader.lines();
 
-    loosing_score * roll_count
-}
+        let a = lines.next().unwrap().unwrap();
+        let b = lines.next().unwrap().unwrap();
 
-const SCORE_DISTRIBUTION: [(usize, usize); 7] =
-    [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+        let (_, a) = a.split_once(':').unwrap();
+        let (_, b) = b.split_once(':').unwrap();
 
-pub fn part2(values: &Input) -> usize {
-    let mut states = vec![(values.clone(), 1)];
-    let mut states_buf = Vec::new();
-    let mut wins = [0; 2];
+        Ok(State::new(a.trim().parse()?, b.trim().parse()?))
+    }
 
-    while !states.is_empty() {
-        for (state, count) in states.drain(..) {
-            for (roll, roll_count) in SCORE_DISTRIBUTION {
-                let mut state = state.clone();
-                let count = count * roll_count;
+    fn part1(values: &Self::Input) -> usize {
+        let mut state = values.clone();
+
+        let mut die = 1;
+        let mut roll_count = 0;
+        let mut roll = || {
+            let result = die;
+            roll_count += 1;
+            die = (die % 100) + 1;
+            result
+        };
+
+        let loosing_score = loop {
+            if let Some(mut player) = state.apply_roll(roll() + roll() + roll(), 1000) {
+                player.swap();
+                break state.score(player);
+            }
+        };
 
-                if let Some(player) = state.apply_roll(roll, 21) {
-                    wins[player.idx()] += count;
-                    continue;
-                }
+        loosing_score * roll_count
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        let mut states = vec![(values.clone(), 1)];
+        let mut states_buf = Vec::new();
+        let mut wins = [0; 2];
+
+        while !states.is_empty() {
+            for (state, count) in states.drain(..) {
+                for (roll, roll_count) in SCORE_DISTRIBUTION {
+                    let mut state = state.clone();
+                    let count = count * roll_count;
 
-                states_buf.push((state, count));
+                    if let Some(player) = state.apply_roll(roll, 21) {
+                        wins[player.idx()] += count;
+                        continue;
+                    }
+
+                    states_buf.push((state, count));
+                }
             }
+
+            swap(&mut states, &mut states_buf);
         }
 
-        swap(&mut states, &mut states_buf);
+        max(wins[0], wins[1])
     }
-
-    max(wins[0], wins[1])
 }
 
 #[cfg(test)]
@@ -79,17 +90,17 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day21::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 739785);
+        assert_eq!(Day21::part1(&input()), 739785);
     }
 
     #[test]
     #[ignore = "takes too long"]
     fn test2() {
-        assert_eq!(part2(&input()), 444356092776315);
+        assert_eq!(Day21::part2(&input()), 444356092776315);
     }
 }
@@ -1 +1 @@
-util::main!(day_21);
+util::main!(day_21::Day21);
@@ -0,0 +1,8 @@
+const INPUT: &str = include_str!("../input.txt");
+const ANSWERS: &str = include_str!("../answers.txt");
+util::check!(
+    day_21::Day21,
+    part1,
+    #[ignore = "takes too long"]
+    part2
+);
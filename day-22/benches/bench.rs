@@ -0,0 +1,2 @@
+const INPUT: &str = include_str!("../input.txt");
+util::bench!(day_22::Day22);
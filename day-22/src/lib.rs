@@ -0,0 +1,120 @@
+use std::{collections::HashMap, io::BufRead};
+
+use anyhow::Result;
+use step::Step;
+use util::{
+    cuboid::{Cuboid, Interval},
+    Solution,
+};
+
+mod step;
+
+type Input = Vec<Step>;
+
+/// Counts the cubes that are on after all `steps`, considering only those inside `region`.
+///
+/// Instead of tracking cubes, this keeps a weight for every cuboid such that the weighted volumes
+/// add up to the lit cubes. Each step cancels out its overlap with all cuboids so far, and lit
+/// cuboids are then added with a weight of one.
+fn lit_cubes(steps: &[Step], region: Option<Cuboid>) -> u64 {
+    let mut weights: HashMap<Cuboid, i64> = HashMap::new();
+
+    for step in steps {
+        let cuboid = match region {
+            Some(region) => match step.cuboid.intersection(&region) {
+                Some(cuboid) => cuboid,
+                None => continue,
+            },
+            None => step.cuboid,
+        };
+
+        let mut update: HashMap<Cuboid, i64> = HashMap::new();
+        for (other, weight) in &weights {
+            if let Some(overlap) = cuboid.intersection(other) {
+                *update.entry(overlap).or_default() -= weight;
+            }
+        }
+        if step.on {
+            *update.entry(cuboid).or_default() += 1;
+        }
+
+        for (cuboid, weight) in update {
+            *weights.entry(cuboid).or_default() += weight;
+        }
+        weights.retain(|_, weight| *weight != 0);
+    }
+
+    // Every cuboid lies within a step, whose volume was checked to fit while parsing.
+    let total: i128 = weights
+        .iter()
+        .map(|(cuboid, &weight)| i128::from(cuboid.volume().unwrap()) * i128::from(weight))
+        .sum();
+    total.try_into().unwrap()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        reader.lines().map(|line| line?.parse()).collect()
+    }
+
+    fn part1(values: &Self::Input) -> u64 {
+        let init = Interval::new(-50, 50).unwrap();
+        lit_cubes(values, Some(Cuboid([init; 3])))
+    }
+
+    fn part2(values: &Self::Input) -> u64 {
+        lit_cubes(values, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const INPUTS: &[&str] = &[
+        include_str!("test_input.txt"),
+        include_str!("test_input_large.txt"),
+    ];
+
+    fn inputs() -> Vec<Input> {
+        INPUTS
+            .iter()
+            .map(|input| Day22::read_input(Cursor::new(input)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn small() {
+        let input = Day22::read_input(Cursor::new(
+            "on x=10..12,y=10..12,z=10..12\n\
+             on x=11..13,y=11..13,z=11..13\n\
+             off x=9..11,y=9..11,z=9..11\n\
+             on x=10..10,y=10..10,z=10..10\n",
+        ))
+        .unwrap();
+        assert_eq!(Day22::part1(&input), 39);
+        assert_eq!(Day22::part2(&input), 39);
+    }
+
+    #[test]
+    fn test1() {
+        for (input, expect) in inputs().into_iter().zip([590784, 474140]) {
+            assert_eq!(Day22::part1(&input), expect);
+        }
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day22::part2(&inputs()[1]), 2758514936282235);
+    }
+}
@@ -0,0 +1 @@
+util::main!(day_22::Day22);
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use util::cuboid::{Cuboid, Interval};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+fn interval(s: &str, axis: char) -> Result<Interval> {
+    let range = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| anyhow!("expected range of {}, found {:?}", axis, s))?;
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| anyhow!("expected `start..end`, found {:?}", range))?;
+    Interval::new(start.parse()?, end.parse()?).ok_or_else(|| anyhow!("empty range {:?}", s))
+}
+
+impl FromStr for Step {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (state, ranges) = s
+            .split_once(' ')
+            .ok_or_else(|| anyhow!("expected `on` or `off` followed by ranges"))?;
+        let on = match state {
+            "on" => true,
+            "off" => false,
+            _ => return Err(anyhow!("unknown state {:?}", state)),
+        };
+
+        let ranges: Vec<_> = ranges.split(',').collect();
+        let [x, y, z] = ranges[..] else {
+            return Err(anyhow!("expected three ranges, found {:?}", ranges));
+        };
+
+        let cuboid = Cuboid([interval(x, 'x')?, interval(y, 'y')?, interval(z, 'z')?]);
+        if cuboid.volume().is_none() {
+            return Err(anyhow!("too many cubes in {:?}", ranges));
+        }
+        Ok(Self { on, cuboid })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "off x=-54112..-39298,y=-85059..-49293,z=5..7"
+                .parse::<Step>()
+                .unwrap(),
+            Step {
+                on: false,
+                cuboid: Cuboid([
+                    Interval::new(-54112, -39298).unwrap(),
+                    Interval::new(-85059, -49293).unwrap(),
+                    Interval::new(5, 7).unwrap(),
+                ])
+            }
+        );
+        assert!("on x=1..2,y=1..2".parse::<Step>().is_err());
+        assert!("on x=2..1,y=1..2,z=1..2".parse::<Step>().is_err());
+        assert!("toggle x=1..2,y=1..2,z=1..2".parse::<Step>().is_err());
+        assert!("on x=0..4294967296,y=0..4294967296,z=1..1"
+            .parse::<Step>()
+            .is_err());
+    }
+}
@@ -0,0 +1,381 @@
+use std::{
+    array,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Result};
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+
+/// Hallway positions in front of a room, which can only be passed.
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    const ALL: [Amphipod; ROOMS] = [Self::Amber, Self::Bronze, Self::Copper, Self::Desert];
+
+    pub fn energy(self) -> usize {
+        match self {
+            Self::Amber => 1,
+            Self::Bronze => 10,
+            Self::Copper => 100,
+            Self::Desert => 1000,
+        }
+    }
+
+    /// Index of the room this amphipod belongs to.
+    pub fn room(self) -> usize {
+        self as usize
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            'A' => Self::Amber,
+            'B' => Self::Bronze,
+            'C' => Self::Copper,
+            'D' => Self::Desert,
+            _ => return None,
+        })
+    }
+
+    fn to_char(self) -> char {
+        (b'A' + self as u8) as char
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Hallway(usize),
+    /// A room and the slot in it, with slot 0 next to the hallway.
+    Room(usize, usize),
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Hallway(x) => write!(f, "hallway {}", x),
+            Location::Room(room, slot) => {
+                write!(f, "room {} slot {}", Amphipod::ALL[*room].to_char(), slot)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub amphipod: Amphipod,
+    pub from: Location,
+    pub to: Location,
+}
+
+impl Move {
+    pub fn energy(&self) -> usize {
+        let (hallway, room, slot) = match (self.from, self.to) {
+            (Location::Hallway(x), Location::Room(room, slot))
+            | (Location::Room(room, slot), Location::Hallway(x)) => (x, room, slot),
+            _ => unreachable!("amphipods move between the hallway and rooms"),
+        };
+        (hallway.abs_diff(door(room)) + slot + 1) * self.amphipod.energy()
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from {} to {}",
+            self.amphipod.to_char(),
+            self.from,
+            self.to
+        )
+    }
+}
+
+/// A burrow with rooms `D` amphipods deep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Burrow<const D: usize> {
+    hallway: [Option<Amphipod>; HALLWAY],
+    rooms: [[Option<Amphipod>; D]; ROOMS],
+}
+
+impl<const D: usize> Burrow<D> {
+    pub fn get(&self, location: Location) -> Option<Amphipod> {
+        match location {
+            Location::Hallway(x) => self.hallway[x],
+            Location::Room(room, slot) => self.rooms[room][slot],
+        }
+    }
+
+    fn get_mut(&mut self, location: Location) -> &mut Option<Amphipod> {
+        match location {
+            Location::Hallway(x) => &mut self.hallway[x],
+            Location::Room(room, slot) => &mut self.rooms[room][slot],
+        }
+    }
+
+    pub fn apply(&self, step: &Move) -> Self {
+        let mut result = *self;
+        *result.get_mut(step.to) = result.get_mut(step.from).take();
+        result
+    }
+
+    pub fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.rooms[room]
+                .iter()
+                .all(|&a| a == Some(Amphipod::ALL[room]))
+        })
+    }
+
+    /// Whether the hallway from `from` to `to` is empty, not counting `from` itself.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[range].iter().all(Option::is_none)
+    }
+
+    /// Whether a room only contains the amphipods it belongs to, if any.
+    fn accepts(&self, room: usize) -> bool {
+        self.rooms[room]
+            .iter()
+            .all(|&a| a.is_none() || a == Some(Amphipod::ALL[room]))
+    }
+
+    /// All legal moves, with the burrow after each of them and their energy.
+    pub fn moves(&self) -> Vec<(Move, Self, usize)> {
+        let mut result = Vec::new();
+        let mut push = |step: Move| result.push((step, self.apply(&step), step.energy()));
+
+        for (x, amphipod) in self.hallway.iter().enumerate() {
+            let Some(amphipod) = *amphipod else {
+                continue;
+            };
+            let room = amphipod.room();
+            if !self.accepts(room) || !self.is_clear(x, door(room)) {
+                continue;
+            }
+            let slot = self.rooms[room].iter().rposition(Option::is_none).unwrap();
+            push(Move {
+                amphipod,
+                from: Location::Hallway(x),
+                to: Location::Room(room, slot),
+            });
+        }
+
+        for room in 0..ROOMS {
+            if self.accepts(room) {
+                continue;
+            }
+            let slot = self.rooms[room].iter().position(Option::is_some).unwrap();
+            let amphipod = self.rooms[room][slot].unwrap();
+            for x in (0..HALLWAY).filter(|&x| !(0..ROOMS).any(|room| door(room) == x)) {
+                if self.is_clear(door(room), x) {
+                    push(Move {
+                        amphipod,
+                        from: Location::Room(room, slot),
+                        to: Location::Hallway(x),
+                    });
+                }
+            }
+        }
+
+        result
+    }
+
+    /// A lower bound of the energy needed to organize the burrow, counting only the steps every
+    /// misplaced amphipod has to take to reach its room.
+    pub fn min_energy(&self) -> usize {
+        let hallway = self.hallway.iter().enumerate().filter_map(|(x, a)| {
+            let a = (*a)?;
+            Some((x.abs_diff(door(a.room())) + 1) * a.energy())
+        });
+
+        let rooms = (0..ROOMS).flat_map(|room| {
+            (0..D).filter_map(move |slot| {
+                let a = self.rooms[room][slot]?;
+                let blocking = self.rooms[room][slot..]
+                    .iter()
+                    .any(|&below| below != Some(a));
+                let steps = if a.room() != room {
+                    slot + 1 + door(room).abs_diff(door(a.room())) + 1
+                } else if blocking {
+                    // Out of the room, aside and back in
+                    slot + 1 + 2 + 1
+                } else {
+                    0
+                };
+                Some(steps * a.energy())
+            })
+        });
+
+        hallway.chain(rooms).sum()
+    }
+}
+
+impl Burrow<2> {
+    /// Inserts the two folded lines of the diagram.
+    pub fn unfold(&self) -> Burrow<4> {
+        use Amphipod::*;
+        const FOLDED: [[Amphipod; 2]; ROOMS] = [
+            [Desert, Desert],
+            [Copper, Bronze],
+            [Bronze, Amber],
+            [Amber, Copper],
+        ];
+
+        let rooms = array::from_fn(|room| {
+            [
+                self.rooms[room][0],
+                Some(FOLDED[room][0]),
+                Some(FOLDED[room][1]),
+                self.rooms[room][1],
+            ]
+        });
+
+        Burrow {
+            hallway: self.hallway,
+            rooms,
+        }
+    }
+}
+
+impl<const D: usize> FromStr for Burrow<D> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().skip(1);
+        let hallway_line = lines.next().ok_or_else(|| anyhow!("missing hallway"))?;
+        let mut hallway = [None; HALLWAY];
+        for (x, c) in hallway_line.chars().skip(1).take(HALLWAY).enumerate() {
+            hallway[x] = Amphipod::from_char(c);
+        }
+
+        let mut slots = Vec::new();
+        for _ in 0..D {
+            let line = lines
+                .next()
+                .ok_or_else(|| anyhow!("expected {} lines of rooms", D))?;
+            let slot = line
+                .chars()
+                .skip(3)
+                .step_by(2)
+                .take(ROOMS)
+                .map(|c| match c {
+                    '.' => Ok(None),
+                    c => Amphipod::from_char(c)
+                        .map(Some)
+                        .ok_or_else(|| anyhow!("unknown amphipod {:?}", c)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if slot.len() != ROOMS {
+                return Err(anyhow!("expected {} rooms in {:?}", ROOMS, line));
+            }
+            slots.push(slot);
+        }
+        let rooms = array::from_fn(|room| array::from_fn(|slot| slots[slot][room]));
+
+        let burrow = Self { hallway, rooms };
+        for amphipod in Amphipod::ALL {
+            let count = burrow
+                .hallway
+                .iter()
+                .chain(burrow.rooms.iter().flatten())
+                .filter(|&&a| a == Some(amphipod))
+                .count();
+            if count != D {
+                return Err(anyhow!("expected {} of {:?}, found {}", D, amphipod, count));
+            }
+        }
+
+        Ok(burrow)
+    }
+}
+
+impl<const D: usize> Display for Burrow<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cell = |a: Option<Amphipod>| a.map_or('.', Amphipod::to_char);
+
+        writeln!(f, "#############")?;
+        let hallway: String = self.hallway.iter().map(|&a| cell(a)).collect();
+        writeln!(f, "#{}#", hallway)?;
+        for slot in 0..D {
+            let [a, b, c, d] = self.rooms.map(|room| cell(room[slot]));
+            let (left, right) = if slot == 0 { ("##", "##") } else { ("  ", "") };
+            writeln!(f, "{left}#{a}#{b}#{c}#{d}#{right}")?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn display() {
+        let burrow: Burrow<2> = INPUT.parse().unwrap();
+        assert_eq!(burrow.to_string(), INPUT.trim_end());
+        assert_eq!(
+            burrow.unfold().to_string(),
+            "\
+#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        );
+        assert!("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#B#\n"
+            .parse::<Burrow<2>>()
+            .is_err());
+    }
+
+    #[test]
+    fn moves() {
+        let burrow: Burrow<2> = INPUT.parse().unwrap();
+        // Each of the four top amphipods can stop at any of the seven hallway spots
+        assert_eq!(burrow.moves().len(), 28);
+
+        let step = Move {
+            amphipod: Amphipod::Bronze,
+            from: Location::Room(2, 0),
+            to: Location::Hallway(3),
+        };
+        assert_eq!(step.energy(), 40);
+
+        // The bronze amphipod now blocks the copper one from going left
+        let targets: Vec<_> = burrow
+            .apply(&step)
+            .moves()
+            .into_iter()
+            .filter(|(m, _, _)| m.from == Location::Room(1, 0))
+            .map(|(m, _, energy)| (m.to, energy))
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                (Location::Hallway(5), 200),
+                (Location::Hallway(7), 400),
+                (Location::Hallway(9), 600),
+                (Location::Hallway(10), 700),
+            ]
+        );
+    }
+}
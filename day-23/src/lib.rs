@@ -0,0 +1,90 @@
+use std::io::BufRead;
+
+use anyhow::Result;
+use burrow::{Burrow, Move};
+use util::{
+    search::{astar, Path},
+    Solution,
+};
+
+pub mod burrow;
+
+type Input = Burrow<2>;
+
+/// The cheapest moves to get every amphipod into its room.
+pub fn organize<const D: usize>(burrow: Burrow<D>) -> Option<Path<Move>> {
+    astar(
+        burrow,
+        Burrow::moves,
+        Burrow::min_energy,
+        Burrow::is_organized,
+    )
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(mut reader: impl BufRead) -> Result<Self::Input> {
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf)?;
+        buf.parse()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        organize(*values).unwrap().cost
+    }
+
+    fn part2(values: &Self::Input) -> usize {
+        organize(values.unfold()).unwrap().cost
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("test_input.txt");
+
+    fn input() -> Input {
+        Day23::read_input(Cursor::new(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day23::part1(&input()), 12521);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day23::part2(&input()), 44169);
+    }
+
+    #[test]
+    fn replay() {
+        let start = input().unfold();
+        let path = organize(start).unwrap();
+
+        let mut burrow = start;
+        let mut energy = 0;
+        for step in &path.steps {
+            let (_, next, cost) = burrow
+                .moves()
+                .into_iter()
+                .find(|(m, _, _)| m == step)
+                .unwrap_or_else(|| panic!("{} is not possible in\n{}", step, burrow));
+            burrow = next;
+            energy += cost;
+        }
+
+        assert!(burrow.is_organized());
+        assert_eq!(energy, path.cost);
+    }
+}
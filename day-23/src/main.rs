@@ -0,0 +1 @@
+util::main!(day_23::Day23);
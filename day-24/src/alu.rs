@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reg {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Reg {
+    pub const ALL: [Reg; 4] = [Reg::W, Reg::X, Reg::Y, Reg::Z];
+
+    pub fn idx(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for Reg {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "w" => Reg::W,
+            "x" => Reg::X,
+            "y" => Reg::Y,
+            "z" => Reg::Z,
+            _ => return Err(anyhow!("unknown register {:?}", s)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Reg(Reg),
+    Value(i64),
+}
+
+impl FromStr for Operand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => Ok(Operand::Reg(s.parse()?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Reg),
+    Op(Op, Reg, Operand),
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split_whitespace().collect();
+        let op = match parts[..] {
+            ["inp", a] => return Ok(Instruction::Inp(a.parse()?)),
+            [op, _, _] => match op {
+                "add" => Op::Add,
+                "mul" => Op::Mul,
+                "div" => Op::Div,
+                "mod" => Op::Mod,
+                "eql" => Op::Eql,
+                _ => return Err(anyhow!("unknown instruction {:?}", op)),
+            },
+            _ => return Err(anyhow!("malformed instruction {:?}", s)),
+        };
+        Ok(Instruction::Op(op, parts[1].parse()?, parts[2].parse()?))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AluError {
+    #[error("ran out of input")]
+    MissingInput,
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("invalid modulo {0} % {1}")]
+    InvalidModulo(i64, i64),
+    #[error("arithmetic overflow")]
+    Overflow,
+}
+
+/// The registers of the ALU.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alu(pub [i64; 4]);
+
+impl Alu {
+    pub fn get(&self, reg: Reg) -> i64 {
+        self.0[reg.idx()]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Reg(reg) => self.get(reg),
+            Operand::Value(value) => value,
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        instruction: Instruction,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let (op, a, b) = match instruction {
+            Instruction::Inp(reg) => {
+                self.0[reg.idx()] = input.next().ok_or(AluError::MissingInput)?;
+                return Ok(());
+            }
+            Instruction::Op(op, a, b) => (op, self.get(a), self.value(b)),
+        };
+
+        let result = match op {
+            Op::Add => a.checked_add(b).ok_or(AluError::Overflow)?,
+            Op::Mul => a.checked_mul(b).ok_or(AluError::Overflow)?,
+            Op::Div if b == 0 => return Err(AluError::DivisionByZero),
+            Op::Div => a.checked_div(b).ok_or(AluError::Overflow)?,
+            Op::Mod if a < 0 || b <= 0 => return Err(AluError::InvalidModulo(a, b)),
+            Op::Mod => a % b,
+            Op::Eql => (a == b).into(),
+        };
+        if let Instruction::Op(_, reg, _) = instruction {
+            self.0[reg.idx()] = result;
+        }
+        Ok(())
+    }
+
+    /// Runs `program` on fresh registers.
+    pub fn run(
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<Self, AluError> {
+        let mut alu = Self::default();
+        alu.resume(program, input)?;
+        Ok(alu)
+    }
+
+    /// Runs `program` on the current registers.
+    pub fn resume(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let mut input = input.into_iter();
+        for &instruction in program {
+            self.execute(instruction, &mut input)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<Instruction>> {
+    s.lines().map(str::parse).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let negate = parse("inp x\nmul x -1").unwrap();
+        assert_eq!(Alu::run(&negate, [7]).unwrap().get(Reg::X), -7);
+
+        let triple = parse("inp z\ninp x\nmul z 3\neql z x").unwrap();
+        assert_eq!(Alu::run(&triple, [2, 6]).unwrap().get(Reg::Z), 1);
+        assert_eq!(Alu::run(&triple, [2, 5]).unwrap().get(Reg::Z), 0);
+
+        let binary = parse(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\n\
+             div w 2\nmod w 2",
+        )
+        .unwrap();
+        assert_eq!(Alu::run(&binary, [13]).unwrap(), Alu([1, 1, 0, 1]));
+    }
+
+    #[test]
+    fn errors() {
+        assert!("sub x 1".parse::<Instruction>().is_err());
+        assert!("add v 1".parse::<Instruction>().is_err());
+        assert!("inp".parse::<Instruction>().is_err());
+
+        let program = parse("inp x\ninp y").unwrap();
+        assert_eq!(Alu::run(&program, [1]), Err(AluError::MissingInput));
+        let program = parse("inp x\ndiv y x").unwrap();
+        assert_eq!(Alu::run(&program, [0]), Err(AluError::DivisionByZero));
+        let program = parse("inp x\nmod x 3").unwrap();
+        assert_eq!(
+            Alu::run(&program, [-1]),
+            Err(AluError::InvalidModulo(-1, 3))
+        );
+
+        let program = parse("inp x\nmul x 9223372036854775807\nadd x 1").unwrap();
+        assert_eq!(Alu::run(&program, [2]), Err(AluError::Overflow));
+        assert_eq!(Alu::run(&program, [-1]).unwrap().get(Reg::X), i64::MIN + 2);
+        let program = parse("inp x\ninp y\nmul x 9223372036854775807\nadd x -1\ndiv x y").unwrap();
+        assert_eq!(Alu::run(&program, [-1, -1]), Err(AluError::Overflow));
+    }
+}
@@ -0,0 +1,356 @@
+use std::{collections::HashSet, ops::Range};
+
+use anyhow::{bail, Result};
+
+use crate::alu::{Alu, Instruction, Op, Operand, Reg};
+
+/// The smallest and largest value a register can hold, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Bounds {
+    const ANY: Self = Self {
+        min: i64::MIN,
+        max: i64::MAX,
+    };
+
+    /// Returns `None` if `max` is smaller than `min`.
+    fn new(min: i64, max: i64) -> Option<Self> {
+        (min <= max).then_some(Self { min, max })
+    }
+
+    fn point(value: i64) -> Self {
+        Self {
+            min: value,
+            max: value,
+        }
+    }
+
+    fn hull<const N: usize>(values: [i64; N]) -> Self {
+        Self {
+            min: values.into_iter().min().unwrap(),
+            max: values.into_iter().max().unwrap(),
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// The bounds of `a op b` for any `a` and `b` within bounds that do not make the ALU fail.
+fn eval(op: Op, a: Bounds, b: Bounds) -> Bounds {
+    let corners = |f: fn(i64, i64) -> i64, b: Bounds| {
+        Bounds::hull([
+            f(a.min, b.min),
+            f(a.min, b.max),
+            f(a.max, b.min),
+            f(a.max, b.max),
+        ])
+    };
+
+    match op {
+        Op::Add => Bounds {
+            min: a.min.saturating_add(b.min),
+            max: a.max.saturating_add(b.max),
+        },
+        Op::Mul => corners(i64::saturating_mul, b),
+        Op::Div => {
+            // Truncating division is monotonic in both operands as long as the divisor keeps its
+            // sign, so the corners of each half of the divisor bound the result.
+            let div = |b| corners(|a, b| a.checked_div(b).unwrap_or(i64::MAX), b);
+            let negative = Bounds::new(b.min, b.max.min(-1)).map(div);
+            let positive = Bounds::new(b.min.max(1), b.max).map(div);
+            match (negative, positive) {
+                (Some(n), Some(p)) => Bounds::hull([n.min, n.max, p.min, p.max]),
+                (n, p) => n.or(p).unwrap_or(Bounds::ANY),
+            }
+        }
+        Op::Mod => {
+            let a = Bounds::new(a.min.max(0), a.max);
+            let b = Bounds::new(b.min.max(1), b.max);
+            match a.zip(b) {
+                Some((a, b)) if a.max < b.min => a,
+                Some((a, b)) => Bounds {
+                    min: 0,
+                    max: a.max.min(b.max - 1),
+                },
+                None => Bounds::ANY,
+            }
+        }
+        Op::Eql if a.max < b.min || b.max < a.min => Bounds::point(0),
+        Op::Eql if a.min == a.max && a == b => Bounds::point(1),
+        Op::Eql => Bounds { min: 0, max: 1 },
+    }
+}
+
+/// A program reading one digit per `inp`, split into one segment per digit.
+///
+/// Before searching for model numbers, the program is run on bounds of values instead of concrete
+/// ones. If that shows that `z` can only grow, except for divisions by constants, a segment
+/// starting with a `z` larger than the product of all remaining divisors can never end with
+/// `z == 0`, and its whole subtree of digits is skipped.
+pub struct Analysis {
+    program: Vec<Instruction>,
+    segments: Vec<Range<usize>>,
+    /// The registers whose value before each segment can still affect the final `z`.
+    live: Vec<[bool; 4]>,
+    /// The bounds of each register after each segment, for any sequence of digits.
+    bounds: Vec<[Bounds; 4]>,
+    /// Before each segment, the smallest `z` that can no longer end at zero, if there is one.
+    limits: Vec<Option<i64>>,
+}
+
+impl Analysis {
+    pub fn new(program: Vec<Instruction>) -> Result<Self> {
+        if !matches!(program.first(), Some(Instruction::Inp(_))) {
+            bail!("the program has to start with an inp instruction");
+        }
+
+        let mut segments = vec![];
+        let mut start = 0;
+        for (i, instruction) in program.iter().enumerate().skip(1) {
+            if let Instruction::Inp(_) = instruction {
+                segments.push(start..i);
+                start = i;
+            }
+        }
+        segments.push(start..program.len());
+
+        // Liveness flows backwards: a register untouched by a segment is live before it if it is
+        // live after it, and only `z` matters once the program ends.
+        let mut live = vec![[false; 4]; segments.len()];
+        let mut after = [false, false, false, true];
+        for (i, segment) in segments.iter().enumerate().rev() {
+            let (read, written) = accesses(&program[segment.clone()]);
+            for reg in 0..4 {
+                live[i][reg] = read[reg] || (after[reg] && !written[reg]);
+            }
+            after = live[i];
+        }
+
+        let mut bounds = Vec::with_capacity(segments.len());
+        let mut divisors = Vec::with_capacity(segments.len());
+        let mut registers = [Bounds::point(0); 4];
+        for segment in &segments {
+            let mut divisor = Some(1i64);
+            for &instruction in &program[segment.clone()] {
+                let (reg, value) = match instruction {
+                    Instruction::Inp(reg) => {
+                        if reg == Reg::Z {
+                            divisor = None;
+                        }
+                        (reg, Bounds { min: 1, max: 9 })
+                    }
+                    Instruction::Op(op, reg, operand) => {
+                        let b = match operand {
+                            Operand::Reg(b) => registers[b.idx()],
+                            Operand::Value(value) => Bounds::point(value),
+                        };
+                        if reg == Reg::Z {
+                            divisor = match (op, operand) {
+                                (Op::Div, Operand::Value(d)) if d > 0 => {
+                                    divisor.and_then(|p| p.checked_mul(d))
+                                }
+                                (Op::Mul, _) if b.min >= 1 => divisor,
+                                (Op::Add, _) if b.min >= 0 => divisor,
+                                _ => None,
+                            };
+                        }
+                        (reg, eval(op, registers[reg.idx()], b))
+                    }
+                };
+                registers[reg.idx()] = value;
+            }
+            bounds.push(registers);
+            divisors.push(divisor);
+        }
+
+        let mut limits = vec![None; segments.len()];
+        let mut limit = Some(1i64);
+        for (i, divisor) in divisors.into_iter().enumerate().rev() {
+            limit = limit.zip(divisor).and_then(|(l, d)| l.checked_mul(d));
+            limits[i] = limit;
+        }
+
+        Ok(Self {
+            program,
+            segments,
+            live,
+            bounds,
+            limits,
+        })
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &[Instruction]> {
+        self.segments.iter().map(|s| &self.program[s.clone()])
+    }
+
+    pub fn bounds(&self) -> &[[Bounds; 4]] {
+        &self.bounds
+    }
+
+    /// The largest sequence of digits leaving `z` at zero, read as a number.
+    pub fn largest(&self) -> Option<u64> {
+        self.search(&[9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    /// The smallest sequence of digits leaving `z` at zero, read as a number.
+    pub fn smallest(&self) -> Option<u64> {
+        self.search(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    fn search(&self, digits: &[i64]) -> Option<u64> {
+        self.find(0, Alu::default(), 0, digits, &mut HashSet::new())
+    }
+
+    /// Depth-first search trying `digits` in order, remembering the states that lead nowhere.
+    fn find(
+        &self,
+        segment: usize,
+        alu: Alu,
+        prefix: u64,
+        digits: &[i64],
+        dead: &mut HashSet<(usize, Alu)>,
+    ) -> Option<u64> {
+        if segment == self.segments.len() {
+            return (alu.get(Reg::Z) == 0).then_some(prefix);
+        }
+        if matches!(self.limits[segment], Some(limit) if alu.get(Reg::Z) >= limit) {
+            return None;
+        }
+
+        // Dead registers cannot change the outcome, so states differing only in them are merged.
+        let mut key = alu;
+        for reg in Reg::ALL {
+            if !self.live[segment][reg.idx()] {
+                key.0[reg.idx()] = 0;
+            }
+        }
+        if dead.contains(&(segment, key)) {
+            return None;
+        }
+
+        for &digit in digits {
+            let mut next = alu;
+            if next
+                .resume(&self.program[self.segments[segment].clone()], [digit])
+                .is_ok()
+            {
+                let number = prefix * 10 + digit as u64;
+                if let Some(result) = self.find(segment + 1, next, number, digits, dead) {
+                    return Some(result);
+                }
+            }
+        }
+
+        dead.insert((segment, key));
+        None
+    }
+}
+
+/// The registers `segment` reads before overwriting them, and those it writes at all.
+fn accesses(segment: &[Instruction]) -> ([bool; 4], [bool; 4]) {
+    let mut read = [false; 4];
+    let mut written = [false; 4];
+
+    for &instruction in segment {
+        let reg = match instruction {
+            Instruction::Inp(reg) => reg,
+            Instruction::Op(op, reg, operand) => {
+                if let Operand::Reg(b) = operand {
+                    read[b.idx()] |= !written[b.idx()];
+                }
+                if (op, operand) != (Op::Mul, Operand::Value(0)) {
+                    read[reg.idx()] |= !written[reg.idx()];
+                }
+                reg
+            }
+        };
+        written[reg.idx()] = true;
+    }
+    (read, written)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::alu::parse;
+
+    fn analyze(program: &str) -> Analysis {
+        Analysis::new(parse(program).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn bounds() {
+        let b = |min, max| Bounds { min, max };
+        assert_eq!(eval(Op::Mul, b(-2, 3), b(-5, 4)), b(-15, 12));
+        assert_eq!(eval(Op::Div, b(-7, 9), b(-2, 3)), b(-9, 9));
+        assert_eq!(eval(Op::Div, b(10, 20), b(0, 5)), b(2, 20));
+        assert_eq!(eval(Op::Mod, b(-3, 100), b(26, 26)), b(0, 25));
+        assert_eq!(eval(Op::Mod, b(3, 7), b(26, 26)), b(3, 7));
+        assert_eq!(eval(Op::Eql, b(1, 9), b(10, 20)), b(0, 0));
+        assert_eq!(eval(Op::Eql, b(1, 9), b(9, 20)), b(0, 1));
+        assert_eq!(eval(Op::Eql, b(4, 4), b(4, 4)), b(1, 1));
+        assert_eq!(eval(Op::Eql, Bounds::ANY, Bounds::ANY), b(0, 1));
+    }
+
+    #[test]
+    fn full_range() {
+        let analysis = analyze("inp w\nmul w -1\nmod w 3\neql w 0\nadd z w");
+        assert_eq!(analysis.largest(), None);
+    }
+
+    #[test]
+    fn liveness() {
+        let (read, written) =
+            accesses(&parse("inp w\nmul x 0\nadd x z\nadd y x\nadd z w").unwrap());
+        assert_eq!(read, [false, false, true, true]);
+        assert_eq!(written, [true, true, true, true]);
+
+        let analysis = analyze("inp w\nadd y w\ninp w\ninp w\nadd z y");
+        assert_eq!(
+            analysis.live,
+            [
+                [false, false, true, true],
+                [false, false, true, true],
+                [false, false, true, true]
+            ]
+        );
+    }
+
+    #[test]
+    fn pass_through() {
+        // y carries the first digit through a segment that does not touch it.
+        let analysis = analyze("inp w\nadd y w\ninp w\ninp w\nadd z y");
+        assert_eq!(analysis.largest(), None);
+
+        let analysis = analyze("inp w\nadd y w\ninp w\ninp w\nadd z y\nadd z -3");
+        assert_eq!(analysis.largest(), Some(399));
+        assert_eq!(analysis.smallest(), Some(311));
+
+        // The last segment never touches z, which has to be zero already.
+        let analysis = analyze("inp z\ninp w");
+        assert_eq!(analysis.largest(), None);
+    }
+
+    #[test]
+    fn limits() {
+        let analysis = analyze("inp w\nadd z w\ninp w\ndiv z 26\ninp w\ndiv z 3\nmul z w");
+        assert_eq!(analysis.limits, [Some(78), Some(78), Some(3)]);
+
+        let analysis = analyze("inp w\nadd z w\ninp w\nadd z -1");
+        assert_eq!(analysis.limits, [None, None]);
+        assert_eq!(analysis.largest(), Some(19));
+        assert_eq!(analysis.smallest(), Some(11));
+
+        assert!(Analysis::new(parse("add z 1\ninp w").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::BufRead;
+
+use analysis::Analysis;
+use anyhow::Result;
+use thiserror::Error;
+use util::{Fallible, Solution};
+
+pub mod alu;
+pub mod analysis;
+
+type Input = Analysis;
+
+/// The program does not leave `z` at zero for any sequence of digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("no model number is valid")]
+pub struct NoModelNumber;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Input;
+    type Answer1 = Fallible<u64, NoModelNumber>;
+    type Answer2 = Fallible<u64, NoModelNumber>;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        let program = reader
+            .lines()
+            .map(|line| line?.parse())
+            .collect::<Result<Vec<_>>>()?;
+        Analysis::new(program)
+    }
+
+    fn part1(values: &Self::Input) -> Self::Answer1 {
+        values.largest().ok_or(NoModelNumber).into()
+    }
+
+    fn part2(values: &Self::Input) -> Self::Answer2 {
+        values.smallest().ok_or(NoModelNumber).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::alu::{Alu, Instruction, Reg};
+
+    const INPUT: &str = include_str!("test_input.txt");
+
+    fn input() -> Input {
+        Day24::read_input(Cursor::new(INPUT)).unwrap()
+    }
+
+    fn digits(number: u64) -> Vec<i64> {
+        number
+            .to_string()
+            .bytes()
+            .map(|b| (b - b'0') as i64)
+            .collect()
+    }
+
+    fn accepts(program: &[Instruction], number: u64) -> bool {
+        Alu::run(program, digits(number)).is_ok_and(|alu| alu.get(Reg::Z) == 0)
+    }
+
+    /// A program shaped like the puzzle input, with one block per `(divisor, check, offset)`.
+    fn monad(blocks: &[(i64, i64, i64)]) -> Analysis {
+        let program: String = blocks
+            .iter()
+            .map(|(divisor, check, offset)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                     mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\n\
+                     add y {}\nmul y x\nadd z y\n",
+                    divisor, check, offset
+                )
+            })
+            .collect();
+        Day24::read_input(Cursor::new(program)).unwrap()
+    }
+
+    /// A simple generator, good enough to sample model numbers.
+    fn samples(seed: u64) -> impl Iterator<Item = u64> {
+        let mut state = seed;
+        std::iter::repeat_with(move || {
+            (0..14).fold(0, |n, _| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                n * 10 + (state >> 33) % 9 + 1
+            })
+        })
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day24::part1(&input()), Fallible(Ok(93999599119697)));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day24::part2(&input()), Fallible(Ok(41991151117131)));
+    }
+
+    #[test]
+    fn interpreter_agrees() {
+        let analysis = input();
+        let program = analysis.program();
+        let (largest, smallest) = (analysis.largest().unwrap(), analysis.smallest().unwrap());
+        assert!(accepts(program, largest));
+        assert!(accepts(program, smallest));
+
+        for number in samples(24).take(2000) {
+            if number > largest || number < smallest {
+                assert!(!accepts(program, number), "{} is accepted", number);
+            }
+        }
+    }
+
+    #[test]
+    fn brute_force() {
+        let programs = [
+            monad(&[(1, 12, 4), (26, -7, 2), (1, 11, 7), (26, -9, 3)]),
+            monad(&[(1, 14, 0), (1, 10, 5), (26, -3, 8), (26, -2, 1)]),
+            monad(&[(1, 12, 4), (1, 11, 7), (26, -5, 2)]),
+        ];
+        for analysis in &programs {
+            let len = analysis.segments().count() as u32;
+            let valid: Vec<_> = (10u64.pow(len - 1)..10u64.pow(len))
+                .filter(|n| !n.to_string().contains('0'))
+                .filter(|&n| accepts(analysis.program(), n))
+                .collect();
+            assert_eq!(analysis.largest(), valid.iter().copied().max());
+            assert_eq!(analysis.smallest(), valid.iter().copied().min());
+        }
+    }
+
+    #[test]
+    fn no_model_number() {
+        let analysis = monad(&[(1, 12, 4), (26, -20, 0)]);
+        assert_eq!(Day24::part1(&analysis), Fallible(Err(NoModelNumber)));
+        assert_eq!(
+            Day24::part2(&analysis).to_string(),
+            "error: no model number is valid"
+        );
+    }
+
+    #[test]
+    fn bounds_hold() {
+        let analysis = input();
+
+        for number in samples(7).take(200) {
+            let mut alu = Alu::default();
+            for ((segment, digit), bounds) in analysis
+                .segments()
+                .zip(digits(number))
+                .zip(analysis.bounds())
+            {
+                alu.resume(segment, [digit]).unwrap();
+                for reg in Reg::ALL {
+                    assert!(bounds[reg.idx()].contains(alu.get(reg)), "{:?}", reg);
+                }
+            }
+        }
+    }
+}
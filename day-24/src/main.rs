@@ -0,0 +1 @@
+util::main!(day_24::Day24);
@@ -1,25 +1,30 @@
-use util::grid::Digit;
+use util::grid::{Digit, Point, Render};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Cucumber {
+    #[default]
     Empty,
     East,
     South,
 }
 
 impl Cucumber {
-    pub fn next_move(self) -> (isize, isize) {
+    pub fn next_move(self) -> Point {
         match self {
             Cucumber::Empty => panic!(),
-            Cucumber::East => (1, 0),
-            Cucumber::South => (0, 1),
+            Cucumber::East => Point::new(1, 0),
+            Cucumber::South => Point::new(0, 1),
         }
     }
 }
 
-impl Default for Cucumber {
-    fn default() -> Self {
-        Self::Empty
+impl Render for Cucumber {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::East => '>',
+            Self::South => 'v',
+        }
     }
 }
 
@@ -1 +1 @@
-util::main!(day_25);
+util::main!(day_25::Day25);
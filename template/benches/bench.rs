@@ -1,2 +1,2 @@
 const INPUT: &str = include_str!("../input.txt");
-util::bench!({{crate_name}});
+util::bench!({{crate_name}}::Day{{ project-name | remove: "day-" }});
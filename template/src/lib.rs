@@ -1,19 +1,30 @@
 use std::io::BufRead;
 
 use anyhow::Result;
+use util::Solution;
 
 type Input = Vec<usize>;
 
-pub fn read_input(reader: impl BufRead) -> Result<Input> {
-    todo!()
-}
+pub struct Day{{ project-name | remove: "day-" }};
 
-pub fn part1(values: &[usize]) -> usize {
-    todo!()
-}
+impl Solution for Day{{ project-name | remove: "day-" }} {
+    const DAY: u8 = {{ project-name | remove: "day-" | plus: 0 }};
+
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input> {
+        todo!()
+    }
+
+    fn part1(values: &Self::Input) -> usize {
+        todo!()
+    }
 
-pub fn part2(values: &[usize]) -> usize {
-    todo!()
+    fn part2(values: &Self::Input) -> usize {
+        todo!()
+    }
 }
 
 #[cfg(test)]
@@ -25,16 +36,16 @@ mod test {
     const INPUT: &str = include_str!("test_input.txt");
 
     fn input() -> Input {
-        read_input(Cursor::new(INPUT)).unwrap()
+        Day{{ project-name | remove: "day-" }}::read_input(Cursor::new(INPUT)).unwrap()
     }
 
     #[test]
     fn test1() {
-        assert_eq!(part1(&input()), 0);
+        assert_eq!(Day{{ project-name | remove: "day-" }}::part1(&input()), 0);
     }
 
     #[test]
     fn test2() {
-        assert_eq!(part2(&input()), 0);
+        assert_eq!(Day{{ project-name | remove: "day-" }}::part2(&input()), 0);
     }
 }
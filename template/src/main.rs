@@ -1 +1 @@
-util::main!({{crate_name}});
+util::main!({{crate_name}}::Day{{ project-name | remove: "day-" }});
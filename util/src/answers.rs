@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+
+use crate::runner::Part;
+
+/// The accepted answers of a day, as stored in its `answers.txt`.
+///
+/// The file uses the same `Part1: <answer>` lines the runner prints. Multi-line answers start with
+/// a bare `Part1:` line, followed by the lines of the answer. Parts without a line have no recorded
+/// answer and are not checked.
+#[derive(Debug, Default)]
+pub struct Answers([Option<String>; 2]);
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.0[part.idx()].as_deref()
+    }
+
+    /// Compares `value` to the recorded answer of `part`.
+    pub fn check(&self, part: Part, value: &str) -> Check {
+        match self.get(part) {
+            None => Check::Unchecked,
+            Some(expected) if expected == value => Check::Ok,
+            Some(expected) => Check::Mismatch(expected.to_owned()),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Self::default();
+        let mut multi_line = None;
+
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let header = line
+                .split_once(':')
+                .and_then(|(part, value)| Some((part.strip_prefix("Part")?, value)));
+
+            match (header, multi_line) {
+                (Some((part, value)), _) => {
+                    let part: Part = part.parse()?;
+                    let value = value.trim_start();
+                    multi_line = value.is_empty().then_some(part);
+                    result.0[part.idx()] = Some(value.to_owned());
+                }
+                (None, Some(part)) => {
+                    let value = result.0[part.idx()].as_mut().unwrap();
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line);
+                }
+                (None, None) => {
+                    return Err(anyhow!("expected `PartN: <answer>` on line {}", i + 1))
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Ok,
+    Unchecked,
+    Mismatch(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers: Answers = "Part1: 42\nPart2: abc def\n".parse().unwrap();
+        assert_eq!(answers.get(Part::One), Some("42"));
+        assert_eq!(answers.get(Part::Two), Some("abc def"));
+
+        let answers: Answers = "Part2: 7\n".parse().unwrap();
+        assert_eq!(answers.get(Part::One), None);
+        assert_eq!(answers.check(Part::One, "1"), Check::Unchecked);
+        assert_eq!(answers.check(Part::Two, "7"), Check::Ok);
+        assert_eq!(
+            answers.check(Part::Two, "8"),
+            Check::Mismatch("7".to_owned())
+        );
+
+        let answers: Answers = "Part1:\n#..#\n####\nPart2: 3\n".parse().unwrap();
+        assert_eq!(answers.get(Part::One), Some("#..#\n####"));
+        assert_eq!(answers.get(Part::Two), Some("3"));
+
+        assert!("Part3: 1".parse::<Answers>().is_err());
+        assert!("42".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,96 @@
+/// A range of integers including both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Returns `None` if `end` is smaller than `start`.
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The number of integers inside, or `None` for the whole range of `i64`, which has one more
+    /// than fits in a `u64`.
+    pub fn size(&self) -> Option<u64> {
+        self.start.abs_diff(self.end).checked_add(1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+/// An axis-aligned box of integer points, `N` intervals wide. Three dimensional by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize = 3>(pub [Interval; N]);
+
+impl<const N: usize> Cuboid<N> {
+    /// The number of integer points inside, or `None` if that does not fit in a `u64`.
+    pub fn volume(&self) -> Option<u64> {
+        self.0.iter().try_fold(1u64, |volume, interval| {
+            volume.checked_mul(interval.size()?)
+        })
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.0.iter().zip(point).all(|(i, v)| i.contains(v))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut result = self.0;
+        for (a, b) in result.iter_mut().zip(&other.0) {
+            *a = a.intersection(b)?;
+        }
+        Some(Self(result))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cuboid(bounds: [(i64, i64); 3]) -> Cuboid {
+        Cuboid(bounds.map(|(start, end)| Interval::new(start, end).unwrap()))
+    }
+
+    #[test]
+    fn intersection() {
+        let a = cuboid([(10, 12), (10, 12), (10, 12)]);
+        let b = cuboid([(11, 13), (11, 13), (11, 13)]);
+        assert_eq!(a.volume(), Some(27));
+        assert_eq!(
+            a.intersection(&b),
+            Some(cuboid([(11, 12), (11, 12), (11, 12)]))
+        );
+        assert_eq!(a.intersection(&cuboid([(13, 14), (0, 20), (0, 20)])), None);
+        assert!(a.contains([10, 11, 12]));
+        assert!(!a.contains([9, 11, 12]));
+
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(Interval::new(-5, 5).unwrap().size(), Some(11));
+    }
+
+    #[test]
+    fn overflow() {
+        let all = Interval::new(i64::MIN, i64::MAX).unwrap();
+        assert_eq!(all.size(), None);
+        assert_eq!(
+            Interval::new(i64::MIN + 1, i64::MAX).unwrap().size(),
+            Some(u64::MAX)
+        );
+
+        let wide = Interval::new(0, 1 << 32).unwrap();
+        assert_eq!(Cuboid([wide; 2]).volume(), None);
+        assert_eq!(Cuboid([Interval::new(1, 1).unwrap(), all]).volume(), None);
+        assert_eq!(
+            Cuboid([wide, Interval::new(1, 2).unwrap()]).volume(),
+            Some(2 << 32 | 2)
+        );
+    }
+}
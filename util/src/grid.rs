@@ -1,8 +1,113 @@
-use std::str::FromStr;
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
 
 use thiserror::Error;
 
-#[derive(Clone)]
+/// A position on a [`Grid`], or an offset between two positions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Offsets of the four orthogonal neighbors.
+pub const ORTHOGONAL: [Point; 4] = [
+    Point::new(0, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(0, 1),
+];
+
+/// Offsets of all eight surrounding cells, row by row.
+pub const ADJACENT: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+/// Offsets of the 3x3 block centered on a cell, including the cell itself, row by row.
+pub const BLOCK: [Point; 9] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(0, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
     width: usize,
     height: usize,
@@ -41,28 +146,162 @@ impl<T> Grid<T> {
         self.content.is_empty()
     }
 
-    fn idx(&self, x: isize, y: isize) -> Option<usize> {
-        if x < 0 || y < 0 || x >= self.width() || y >= self.height() as isize {
+    pub fn contains(&self, pos: Point) -> bool {
+        (0..self.width()).contains(&pos.x) && (0..self.height()).contains(&pos.y)
+    }
+
+    fn idx(&self, pos: Point) -> Option<usize> {
+        if !self.contains(pos) {
             return None;
         }
 
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
+        let x: usize = pos.x.try_into().unwrap();
+        let y: usize = pos.y.try_into().unwrap();
 
         Some(x + y * self.width)
     }
 
-    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
-        self.idx(x, y).map(|i| &self.content[i])
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.idx(pos).map(|i| &self.content[i])
     }
 
-    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
-        self.idx(x, y).map(|i| &mut self.content[i])
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.idx(pos).map(|i| &mut self.content[i])
     }
 
     pub fn for_each<F: FnMut(&T)>(&self, f: F) {
         self.content.iter().for_each(f);
     }
+
+    /// All positions inside the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width();
+        (0..self.height()).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.content)
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.positions().zip(&mut self.content)
+    }
+
+    /// The rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.content[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The columns of the grid, from left to right, each from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        // Without rows, `x` can be past the end of the content.
+        (0..self.width).map(|x| {
+            let column = self.content.get(x..).unwrap_or_default();
+            column.iter().step_by(self.width)
+        })
+    }
+
+    /// Wraps a position around the edges, as if the grid repeated in every direction.
+    ///
+    /// Panics if the grid is empty.
+    pub fn wrap(&self, pos: Point) -> Point {
+        Point::new(
+            pos.x.rem_euclid(self.width()),
+            pos.y.rem_euclid(self.height()),
+        )
+    }
+
+    /// The positions at `offsets` from `pos` that are inside the grid.
+    ///
+    /// Unlike [`stencil`](Self::stencil), the iterator does not borrow the grid, so the cells can
+    /// be modified along the way.
+    pub fn positions_around<'a>(
+        &self,
+        pos: Point,
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        let (width, height) = (self.width(), self.height());
+        offsets
+            .iter()
+            .map(move |&offset| pos + offset)
+            .filter(move |p| (0..width).contains(&p.x) && (0..height).contains(&p.y))
+    }
+
+    /// The positions at `offsets` from `pos`, wrapped around the edges.
+    pub fn wrapping_positions_around<'a>(
+        &self,
+        pos: Point,
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        let (width, height) = (self.width(), self.height());
+        offsets.iter().map(move |&offset| {
+            let p = pos + offset;
+            Point::new(p.x.rem_euclid(width), p.y.rem_euclid(height))
+        })
+    }
+
+    /// The positions and values of the cells at `offsets` from `pos`, skipping those outside the
+    /// grid.
+    pub fn stencil<'a>(
+        &'a self,
+        pos: Point,
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.positions_around(pos, offsets).map(|p| (p, &self[p]))
+    }
+
+    /// The positions and values of the cells at `offsets` from `pos`, wrapped around the edges.
+    pub fn wrapping_stencil<'a>(
+        &'a self,
+        pos: Point,
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.wrapping_positions_around(pos, offsets)
+            .map(|p| (p, &self[p]))
+    }
+
+    /// The orthogonal neighbors of `pos` inside the grid.
+    pub fn neighbors(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.stencil(pos, &ORTHOGONAL)
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` inside the grid.
+    pub fn adjacent(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.stencil(pos, &ADJACENT)
+    }
+
+    /// The orthogonal neighbors of `pos`, wrapped around the edges.
+    pub fn wrapping_neighbors(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.wrapping_stencil(pos, &ORTHOGONAL)
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos`, wrapped around the edges.
+    pub fn wrapping_adjacent(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.wrapping_stencil(pos, &ADJACENT)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` is outside the grid.
+    fn index(&self, pos: Point) -> &Self::Output {
+        match self.idx(pos) {
+            Some(i) => &self.content[i],
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        match self.idx(pos) {
+            Some(i) => &mut self.content[i],
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
 }
 
 impl<T: Default + Clone> Grid<T> {
@@ -135,3 +374,168 @@ impl<T: Digit> FromStr for Grid<T> {
         Grid::from_str_predicate(s, Digit::from_char)
     }
 }
+
+/// The counterpart of [`Digit`], drawing a cell as a single character.
+///
+/// Types implementing both have to map `from_char(to_char())` back to the same cell, so that a
+/// displayed grid parses to an equal grid.
+pub trait Render {
+    fn to_char(&self) -> char;
+}
+
+impl Render for u8 {
+    /// Panics if the value is not a single digit.
+    fn to_char(&self) -> char {
+        char::from_digit((*self).into(), 10).unwrap()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draws each cell with `f`, one line per row, in the format parsed by `FromStr`.
+    pub fn render<'a, F: Fn(&T) -> char + 'a>(&'a self, f: F) -> impl Display + 'a {
+        Rendered(self, f)
+    }
+}
+
+struct Rendered<'a, T, F>(&'a Grid<T>, F);
+
+impl<T, F: Fn(&T) -> char> Display for Rendered<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.0.rows() {
+            let line: String = row.iter().map(&self.1).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Render> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(Render::to_char).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        "123\n456\n".parse().unwrap()
+    }
+
+    fn values<'a>(it: impl Iterator<Item = (Point, &'a u8)>) -> Vec<u8> {
+        it.map(|(_, v)| *v).collect()
+    }
+
+    #[test]
+    fn points() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a * 2, Point::new(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(Point::from((3, 4)), Point::new(3, 4));
+    }
+
+    #[test]
+    fn access() {
+        let mut grid = grid();
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        grid[Point::new(0, 1)] = 7;
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let _ = grid()[Point::new(3, 1)];
+    }
+
+    #[test]
+    fn iterators() {
+        let mut grid = grid();
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)].map(Point::from)
+        );
+        assert!(grid.iter().all(|(pos, v)| grid[pos] == *v));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+
+        for (pos, v) in grid.iter_mut() {
+            *v = (pos.x * 10 + pos.y) as u8;
+        }
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 10, 20], [1, 11, 21]]);
+    }
+
+    #[test]
+    fn empty() {
+        for (width, height) in [(0, 0), (3, 0), (0, 2)] {
+            let grid = Grid::<u8>::new(width, height);
+            assert!(grid.is_empty());
+            assert_eq!(grid.iter().count(), 0);
+            assert_eq!(grid.rows().count(), height as usize);
+            assert!(grid.rows().all(|row| row.is_empty()));
+            assert_eq!(grid.columns().count(), width as usize);
+            assert!(grid.columns().all(|mut column| column.next().is_none()));
+        }
+    }
+
+    #[test]
+    fn display() {
+        for s in ["123\n456\n", "0\n9\n", "\n\n", ""] {
+            let grid: Grid<u8> = s.parse().unwrap();
+            assert_eq!(grid.to_string(), s);
+            assert_eq!(grid.to_string().parse::<Grid<u8>>().unwrap(), grid);
+        }
+
+        let grid = grid();
+        assert_eq!(
+            grid.render(|v| if v % 2 == 0 { '#' } else { '.' })
+                .to_string(),
+            ".#.\n#.#\n"
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+        assert_eq!(values(grid.neighbors(Point::new(0, 0))), [2, 4]);
+        assert_eq!(values(grid.neighbors(Point::new(1, 1))), [2, 4, 6]);
+        assert_eq!(values(grid.adjacent(Point::new(1, 0))), [1, 3, 4, 5, 6]);
+        assert_eq!(values(grid.stencil(Point::new(2, 1), &BLOCK)), [2, 3, 5, 6]);
+        assert_eq!(
+            grid.neighbors(Point::new(2, 1))
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            [Point::new(2, 0), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = grid();
+        assert_eq!(grid.wrap(Point::new(3, -1)), Point::new(0, 1));
+        assert_eq!(grid.wrap(Point::new(-4, 5)), Point::new(2, 1));
+        assert_eq!(
+            values(grid.wrapping_neighbors(Point::new(0, 0))),
+            [4, 3, 2, 4]
+        );
+        assert_eq!(
+            values(grid.wrapping_adjacent(Point::new(2, 1))),
+            [2, 3, 1, 5, 4, 2, 3, 1]
+        );
+        assert_eq!(
+            grid.wrapping_positions_around(Point::new(0, 1), &[Point::new(-1, 1)])
+                .collect::<Vec<_>>(),
+            [Point::new(2, 0)]
+        );
+    }
+}
@@ -1,4 +1,11 @@
+pub mod answers;
+pub mod cuboid;
 pub mod grid;
+pub mod runner;
+pub mod search;
+mod solution;
+
+pub use solution::{Fallible, NoAnswer, Solution};
 
 pub fn gauss_sum(n: usize) -> usize {
     (n * (n + 1)) / 2
@@ -6,39 +13,83 @@ pub fn gauss_sum(n: usize) -> usize {
 
 #[macro_export]
 macro_rules! main {
-    ($id:ident) => {
+    ($t:ty) => {
         use anyhow::Result;
-        use std::io;
-        use $id::{part1, part2, read_input};
 
         fn main() -> Result<()> {
-            let input = read_input(io::stdin().lock())?;
-            println!("Part1: {}", part1(&input));
-            println!("Part2: {}", part2(&input));
-            Ok(())
+            $crate::runner::main(
+                &$crate::runner::Day::new::<$t>(),
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            )
         }
     };
 }
 
+#[macro_export]
+macro_rules! check {
+    ($t:ty) => {
+        $crate::check!($t, part1, part2);
+    };
+    ($t:ty $(, $(#[$attr:meta])* $part:ident)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $part() {
+                use std::io::Cursor;
+
+                use $crate::{answers::Answers, runner::Part, Solution};
+
+                let answers: Answers = ANSWERS.parse().unwrap();
+                let part: Part = stringify!($part)[4..].parse().unwrap();
+                if !<$t>::has_part(part) {
+                    return;
+                }
+                if let Some(expected) = answers.get(part) {
+                    let input = <$t>::read_input(Cursor::new(INPUT)).unwrap();
+                    assert_eq!(
+                        <$t>::$part(&input).to_string(),
+                        expected,
+                        "{} of day {} does not match the recorded answer",
+                        part,
+                        <$t>::DAY,
+                    );
+                }
+            }
+        )*
+    };
+}
+
 #[macro_export]
 macro_rules! bench {
-    ($id:ident) => {
+    (@group $t:ty, $name:expr) => {
         use std::io::Cursor;
 
         use criterion::{black_box, criterion_group, criterion_main, Criterion};
-        use $id::{part1, part2, read_input};
+        use $crate::{runner::Part, Solution};
 
         pub fn benchmark(c: &mut Criterion) {
-            let mut group = c.benchmark_group(format!("Day {:02}", &stringify!($id)[4..]));
+            let mut group = c.benchmark_group($name);
 
-            group.bench_function("Parsing", |b| b.iter(|| read_input(Cursor::new(INPUT))));
+            group.bench_function("Parsing", |b| {
+                b.iter(|| <$t>::read_input(Cursor::new(INPUT)))
+            });
 
-            let values = read_input(Cursor::new(INPUT)).unwrap();
-            group.bench_function("Part 1", |b| b.iter(|| part1(black_box(&values))));
-            group.bench_function("Part 2", |b| b.iter(|| part2(black_box(&values))));
+            let values = <$t>::read_input(Cursor::new(INPUT)).unwrap();
+            if <$t>::has_part(Part::One) {
+                group.bench_function("Part 1", |b| b.iter(|| <$t>::part1(black_box(&values))));
+            }
+            if <$t>::has_part(Part::Two) {
+                group.bench_function("Part 2", |b| b.iter(|| <$t>::part2(black_box(&values))));
+            }
         }
 
         criterion_group!(benches, benchmark);
         criterion_main!(benches);
     };
+    ($t:ty) => {
+        $crate::bench!(@group $t, format!("Day {:02}", <$t>::DAY));
+    };
+    ($t:ty, $label:literal) => {
+        $crate::bench!(@group $t, format!("Day {:02} ({})", <$t>::DAY, $label));
+    };
 }
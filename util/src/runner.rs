@@ -0,0 +1,484 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, IsTerminal},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+
+use crate::{
+    answers::{Answers, Check},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn idx(self) -> usize {
+        match self {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(anyhow!("part must be 1 or 2, found {}", s)),
+        })
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Part{}",
+            match self {
+                Part::One => 1,
+                Part::Two => 2,
+            }
+        )
+    }
+}
+
+/// Runs `f` and returns its result together with the wall-clock time it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub time: Duration,
+}
+
+impl Answer {
+    pub fn measure<T: Display>(part: Part, f: impl FnOnce() -> T) -> Self {
+        let (value, time) = timed(f);
+        Self {
+            part,
+            value: value.to_string(),
+            time,
+        }
+    }
+}
+
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    fn part_time(&self, part: Part) -> Option<Duration> {
+        self.answers.iter().find(|a| a.part == part).map(|a| a.time)
+    }
+
+    fn total(&self) -> Duration {
+        self.parse + self.answers.iter().map(|a| a.time).sum::<Duration>()
+    }
+}
+
+type Solver = fn(&mut dyn BufRead, &[Part]) -> Result<(Duration, Vec<Answer>)>;
+
+fn solve<S: Solution>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<(Duration, Vec<Answer>)> {
+    let (input, parse) = timed(|| S::read_input(reader));
+    let input = input?;
+    let answers = parts
+        .iter()
+        .filter(|&&part| S::has_part(part))
+        .map(|&part| match part {
+            Part::One => Answer::measure(part, || S::part1(&input)),
+            Part::Two => Answer::measure(part, || S::part2(&input)),
+        })
+        .collect();
+    Ok((parse, answers))
+}
+
+/// A type-erased [`Solution`], as registered with the runner.
+pub struct Day {
+    pub number: u8,
+    solver: Solver,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solver: solve::<S>,
+        }
+    }
+
+    /// Parses the input and returns the answers of the requested parts in the same order, leaving
+    /// out parts without a puzzle.
+    pub fn solve(&self, reader: &mut dyn BufRead, parts: &[Part]) -> Result<Report> {
+        let (parse, answers) = (self.solver)(reader, parts)?;
+        Ok(Report {
+            day: self.number,
+            parse,
+            answers,
+        })
+    }
+
+    fn dir(&self, root: &Path) -> PathBuf {
+        root.join(format!("day-{:02}", self.number))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Selection {
+    All,
+    Days(RangeInclusive<u8>),
+}
+
+impl Selection {
+    fn contains(&self, day: u8) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Days(range) => range.contains(&day),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        Ok(Selection::Days(match s.split_once('-') {
+            Some((start, end)) => start.parse()?..=end.parse()?,
+            None => {
+                let day = s.parse()?;
+                day..=day
+            }
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+struct Options {
+    /// Only run the given part (1 or 2)
+    #[clap(short, long)]
+    part: Option<Part>,
+    /// Print a table with the time spent parsing and solving
+    #[clap(short, long)]
+    time: bool,
+    /// Compare the answers against the recorded `answers.txt`
+    #[clap(short, long)]
+    check: bool,
+    /// Output format; `json` prints one object per day, including all timings
+    #[clap(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+impl Options {
+    fn parts(&self) -> Vec<Part> {
+        self.part
+            .map_or_else(|| Part::ALL.to_vec(), |part| vec![part])
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(about = "Run a single Advent of Code solution")]
+struct SingleCli {
+    /// Input file, or `-` for stdin [default: stdin, or the day's `input.txt` if stdin is a
+    /// terminal]
+    #[clap(value_name = "INPUT")]
+    input: Option<PathBuf>,
+    #[clap(flatten)]
+    options: Options,
+}
+
+impl SingleCli {
+    fn reader(&self, dir: &Path) -> Result<Box<dyn BufRead>> {
+        let path = match &self.input {
+            Some(path) if path.as_os_str() == "-" => None,
+            Some(path) => Some(path.clone()),
+            None if io::stdin().is_terminal() => Some(dir.join("input.txt")),
+            None => None,
+        };
+
+        Ok(match path {
+            Some(path) => Box::new(BufReader::new(open(&path)?)),
+            None => Box::new(io::stdin().lock()),
+        })
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(name = "aoc", about = "Run Advent of Code 2021 solutions")]
+struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solutions of the selected days
+    Run {
+        /// Days to run, as numbers, ranges like `3-7`, or `all`
+        #[clap(required = true)]
+        days: Vec<Selection>,
+        /// Directory containing the `day-NN/input.txt` and `day-NN/answers.txt` files
+        #[clap(long, value_name = "DIR")]
+        inputs: Option<PathBuf>,
+        #[clap(flatten)]
+        options: Options,
+    },
+    /// List the available days
+    List,
+}
+
+fn open(path: &Path) -> Result<File> {
+    File::open(path).with_context(|| format!("could not open {}", path.display()))
+}
+
+fn read_answers(dir: &Path) -> Result<Answers> {
+    let path = dir.join("answers.txt");
+    std::fs::read_to_string(&path)
+        .with_context(|| format!("could not read {}", path.display()))?
+        .parse()
+        .with_context(|| format!("could not parse {}", path.display()))
+}
+
+fn print_value(label: &str, value: &str, status: &str) {
+    if value.contains('\n') {
+        println!("{}:{}", label, status);
+        println!("{}", value);
+    } else {
+        println!("{}: {}{}", label, value, status);
+    }
+}
+
+fn print_text(report: &Report, checks: &[Option<Check>]) {
+    for (answer, check) in report.answers.iter().zip(checks) {
+        let label = answer.part.to_string();
+        match check {
+            None => print_value(&label, &answer.value, ""),
+            Some(Check::Ok) => print_value(&label, &answer.value, " [ok]"),
+            Some(Check::Unchecked) => print_value(&label, &answer.value, " [unchecked]"),
+            Some(Check::Mismatch(expected)) if !expected.contains('\n') => print_value(
+                &label,
+                &answer.value,
+                &format!(" [MISMATCH, expected {}]", expected),
+            ),
+            Some(Check::Mismatch(expected)) => {
+                print_value(&label, &answer.value, " [MISMATCH]");
+                print_value("Expected", expected, "");
+            }
+        }
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn print_json(report: &Report, checks: &[Option<Check>]) {
+    let parts: Vec<_> = report
+        .answers
+        .iter()
+        .zip(checks)
+        .map(|(answer, check)| {
+            let mut result = json!({
+                "part": answer.part.idx() + 1,
+                "answer": answer.value,
+                "time_ns": nanos(answer.time),
+            });
+            match check {
+                None => {}
+                Some(Check::Ok) => result["check"] = json!("ok"),
+                Some(Check::Unchecked) => result["check"] = json!("unchecked"),
+                Some(Check::Mismatch(expected)) => {
+                    result["check"] = json!("mismatch");
+                    result["expected"] = json!(expected);
+                }
+            }
+            result
+        })
+        .collect();
+
+    println!(
+        "{}",
+        json!({
+            "day": report.day,
+            "parse_ns": nanos(report.parse),
+            "total_ns": nanos(report.total()),
+            "parts": parts,
+        })
+    );
+}
+
+/// Prints the answers of `report`, returning the number of mismatches against `answers`.
+fn print_report(report: &Report, answers: Option<&Answers>, format: Format) -> usize {
+    let checks: Vec<_> = report
+        .answers
+        .iter()
+        .map(|answer| answers.map(|answers| answers.check(answer.part, &answer.value)))
+        .collect();
+
+    match format {
+        Format::Text => print_text(report, &checks),
+        Format::Json => print_json(report, &checks),
+    }
+
+    checks
+        .iter()
+        .filter(|check| matches!(check, Some(Check::Mismatch(_))))
+        .count()
+}
+
+fn check_mismatches(mismatches: usize) -> Result<()> {
+    if mismatches > 0 {
+        bail!(
+            "{} answer(s) did not match the recorded answers",
+            mismatches
+        );
+    }
+    Ok(())
+}
+
+fn print_row(cells: [String; 5]) {
+    let [day, parse, part1, part2, total] = cells;
+    println!(
+        "{:<5} {:>10} {:>10} {:>10} {:>10}",
+        day, parse, part1, part2, total
+    );
+}
+
+fn print_timing_row(name: String, times: [Option<Duration>; 4]) {
+    let [parse, part1, part2, total] =
+        times.map(|time| time.map_or_else(|| "-".to_owned(), |d| format!("{:.2?}", d)));
+    print_row([name, parse, part1, part2, total]);
+}
+
+fn print_timings(reports: &[Report]) {
+    println!();
+    print_row(["Day", "Parse", "Part 1", "Part 2", "Total"].map(str::to_owned));
+
+    let mut sum = [None; 4];
+    for report in reports {
+        let times = [
+            Some(report.parse),
+            report.part_time(Part::One),
+            report.part_time(Part::Two),
+            Some(report.total()),
+        ];
+        for (sum, time) in sum.iter_mut().zip(times) {
+            if let Some(time) = time {
+                *sum = Some(sum.unwrap_or_default() + time);
+            }
+        }
+        print_timing_row(format!("{:02}", report.day), times);
+    }
+
+    if reports.len() > 1 {
+        print_timing_row("Total".to_owned(), sum);
+    }
+}
+
+/// Entry point of the per-day binaries generated by [`main!`](crate::main).
+///
+/// `dir` is the directory of the day's crate, containing its `input.txt` and `answers.txt`.
+pub fn main(day: &Day, dir: &Path) -> Result<()> {
+    let matches = SingleCli::command()
+        .name(format!("day-{:02}", day.number))
+        .get_matches();
+    let cli = SingleCli::from_arg_matches(&matches)?;
+    let answers = cli.options.check.then(|| read_answers(dir)).transpose()?;
+    let report = day.solve(&mut cli.reader(dir)?, &cli.options.parts())?;
+    let mismatches = print_report(&report, answers.as_ref(), cli.options.format);
+
+    if cli.options.time && cli.options.format == Format::Text {
+        print_timings(&[report]);
+    }
+
+    check_mismatches(mismatches)
+}
+
+/// Entry point of the `aoc` binary, dispatching to every registered day.
+///
+/// Inputs and answers are looked up as `day-NN/input.txt` and `day-NN/answers.txt` below
+/// `input_dir`, unless overridden with `--inputs`.
+pub fn aoc(days: &[Day], input_dir: &Path) -> Result<()> {
+    match Cli::parse().command {
+        Command::List => {
+            for day in days {
+                println!("Day {:02}", day.number);
+            }
+        }
+        Command::Run {
+            days: selection,
+            inputs,
+            options,
+        } => {
+            for s in &selection {
+                if let Selection::Days(range) = s {
+                    if !days.iter().any(|day| range.contains(&day.number)) {
+                        if range.start() == range.end() {
+                            bail!("no solution for day {}", range.start());
+                        }
+                        bail!("no solution for days {}-{}", range.start(), range.end());
+                    }
+                }
+            }
+
+            let input_dir = inputs.as_deref().unwrap_or(input_dir);
+            let parts = options.parts();
+            let mut reports = Vec::new();
+            let mut mismatches = 0;
+            for day in days
+                .iter()
+                .filter(|day| selection.iter().any(|s| s.contains(day.number)))
+            {
+                let dir = day.dir(input_dir);
+                let answers = options.check.then(|| read_answers(&dir)).transpose()?;
+                let file = open(&dir.join("input.txt"))?;
+
+                if options.format == Format::Text {
+                    println!("Day {:02}", day.number);
+                }
+                let report = day.solve(&mut BufReader::new(file), &parts)?;
+                mismatches += print_report(&report, answers.as_ref(), options.format);
+                reports.push(report);
+            }
+
+            if options.time && options.format == Format::Text {
+                print_timings(&reports);
+            }
+
+            check_mismatches(mismatches)?;
+        }
+    }
+
+    Ok(())
+}
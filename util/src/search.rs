@@ -0,0 +1,126 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+/// A cheapest way to a goal, as returned by [`astar`] and [`dijkstra`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<M> {
+    pub cost: usize,
+    pub steps: Vec<M>,
+}
+
+struct Visit<N, M> {
+    node: N,
+    cost: usize,
+    parent: Option<usize>,
+    step: Option<M>,
+}
+
+/// Finds the cheapest sequence of steps from `start` to a node satisfying `is_goal`.
+///
+/// `successors` lists the steps possible from a node, as the step itself, the node it leads to
+/// and its cost. `heuristic` has to be a lower bound of the remaining cost to a goal, or the
+/// returned path might not be the cheapest one.
+pub fn astar<N, M, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<M>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (M, N, usize)>,
+{
+    let mut best = HashMap::from([(start.clone(), 0)]);
+    let mut todo = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut visits = vec![Visit {
+        node: start,
+        cost: 0,
+        parent: None,
+        step: None,
+    }];
+
+    while let Some(Reverse((_, index))) = todo.pop() {
+        let Visit { node, cost, .. } = &visits[index];
+        let cost = *cost;
+        // Skip visits that were superseded by a cheaper way to the same node
+        if best[node] < cost {
+            continue;
+        }
+
+        if is_goal(node) {
+            let mut steps = Vec::new();
+            let mut current = Some(index);
+            while let Some(index) = current {
+                steps.extend(visits[index].step.take());
+                current = visits[index].parent;
+            }
+            steps.reverse();
+            return Some(Path { cost, steps });
+        }
+
+        for (step, next, step_cost) in successors(node) {
+            let next_cost = cost + step_cost;
+            match best.entry(next.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(next_cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+            }
+
+            todo.push(Reverse((next_cost + heuristic(&next), visits.len())));
+            visits.push(Visit {
+                node: next,
+                cost: next_cost,
+                parent: Some(index),
+                step: Some(step),
+            });
+        }
+    }
+
+    None
+}
+
+/// [`astar`] without a heuristic.
+pub fn dijkstra<N, M, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<M>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (M, N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Steps on a number line: `+1` costs 3, `*2` costs 5.
+    fn successors(&n: &u32) -> Vec<(char, u32, usize)> {
+        vec![('+', n + 1, 3), ('*', n * 2, 5)]
+    }
+
+    #[test]
+    fn path() {
+        let path = dijkstra(1, successors, |&n| n == 10).unwrap();
+        assert_eq!(path.steps, ['+', '*', '+', '*']);
+        assert_eq!(path.cost, 16);
+
+        let path = astar(1, successors, |&n| (10 - n.min(10)) as usize, |&n| n == 10).unwrap();
+        assert_eq!(path.cost, 16);
+
+        assert_eq!(
+            dijkstra(0, |_| Vec::<((), u32, usize)>::new(), |&n| n == 1),
+            None
+        );
+        assert_eq!(dijkstra(0, successors, |&n| n == 0).unwrap().steps, []);
+    }
+}
@@ -0,0 +1,101 @@
+use std::{any::TypeId, fmt::Display, io::BufRead};
+
+use anyhow::Result;
+
+use crate::runner::Part;
+
+/// A puzzle solution, implemented by every day.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    type Input;
+    /// The answer of part 1, or [`NoAnswer`] if there is no such puzzle.
+    type Answer1: Display + 'static;
+    /// The answer of part 2, or [`NoAnswer`] if there is no such puzzle.
+    type Answer2: Display + 'static;
+
+    fn read_input(reader: impl BufRead) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Whether `part` has a puzzle to solve. The runner, `bench!` and `check!` skip parts without.
+    fn has_part(part: Part) -> bool {
+        let answer = match part {
+            Part::One => TypeId::of::<Self::Answer1>(),
+            Part::Two => TypeId::of::<Self::Answer2>(),
+        };
+        answer != TypeId::of::<NoAnswer>()
+    }
+}
+
+/// The answer of a part without a puzzle, like the second part of the last day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("-")
+    }
+}
+
+/// The answer of a part that can fail on some inputs, displaying the error in place of a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fallible<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for Fallible<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Self(result)
+    }
+}
+
+impl<T: Display, E: Display> Display for Fallible<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(value) => value.fmt(f),
+            Err(e) => write!(f, "error: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Last;
+
+    impl Solution for Last {
+        const DAY: u8 = 25;
+
+        type Input = ();
+        type Answer1 = usize;
+        type Answer2 = NoAnswer;
+
+        fn read_input(_: impl BufRead) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> usize {
+            1
+        }
+
+        fn part2(_: &Self::Input) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    #[test]
+    fn has_part() {
+        assert!(Last::has_part(Part::One));
+        assert!(!Last::has_part(Part::Two));
+    }
+
+    #[test]
+    fn fallible() {
+        assert_eq!(Fallible::<_, &str>(Ok(42)).to_string(), "42");
+        assert_eq!(
+            Fallible::<u8, _>(Err("overflow")).to_string(),
+            "error: overflow"
+        );
+    }
+}